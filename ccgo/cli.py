@@ -11,6 +11,7 @@
  
 import os
 import sys
+import time
 import importlib
 import argparse
 # setup path
@@ -25,6 +26,52 @@ PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
 from utils.context.namespace import CliNameSpace
 from utils.context.context import CliContext
 from utils.context.command import CliCommand
+from utils.log.log_util import LOG_LEVELS
+from utils.log.log_util import LOG_FORMATS
+from utils.log.log_util import setup_logging
+from utils.log.log_util import get_logger
+from utils.net.http_util import apply_net_env
+from utils.error.error_code import ERROR_CODES
+from utils.error.error_code import format_error_code
+from utils.error.error_code import SUBSYSTEM_ERROR_CODES
+from utils.error.exit_code import ExitCode
+from utils.journal.journal_util import record_invocation
+from utils.trace.trace_util import start_trace
+from utils.i18n.i18n_util import LOCALES
+from utils.i18n.i18n_util import detect_locale
+from utils.i18n.i18n_util import set_locale
+from utils.i18n.i18n_util import tr
+
+# global options of all subcommands, and whether they take a value
+GLOBAL_OPTIONS = {
+    "--log-level": True,
+    "--log-file": True,
+    "--log-format": True,
+    "--trace": False,
+    "--locale": True,
+}
+
+
+def strip_global_options(argv) -> list:
+    # subcommands parse sys.argv again, drop the global options and their
+    # values so they never become a positional, but keep the args after "--"
+    result = []
+    index = 0
+    while index < len(argv):
+        arg = argv[index]
+        if arg == "--":
+            result.extend(argv[index:])
+            break
+        name = arg.split("=", 1)[0]
+        if name in GLOBAL_OPTIONS:
+            if GLOBAL_OPTIONS[name] and "=" not in arg:
+                index += 1
+            index += 1
+            continue
+        result.append(arg)
+        index += 1
+    return result
+
 
 # Root Class for Command Line Interface
 class Cli(CliCommand):
@@ -50,12 +97,48 @@ class Cli(CliCommand):
             'subcommand', metavar=f"{self.get_command_list()}",
             type=str, choices=self.get_command_list(),
         )
+        parser.add_argument(
+            "--log-level",
+            action="store",
+            choices=LOG_LEVELS,
+            help="level of messages shown on console, default is info or CCGO_LOG_LEVEL",
+        )
+        parser.add_argument(
+            "--log-file",
+            action="store",
+            help="also write all messages with timestamps and the build tools output to this file",
+        )
+        parser.add_argument(
+            "--log-format",
+            action="store",
+            default="text",
+            choices=LOG_FORMATS,
+            help="format of log messages, json is one object per line",
+        )
+        parser.add_argument(
+            "--trace",
+            action="store_true",
+            help="record the external commands with their args, cwd, environment changes, duration "
+                 "and exit code to .ccgo/trace.jsonl, see `ccgo trace`",
+        )
+        parser.add_argument(
+            "--locale",
+            action="store",
+            choices=LOCALES,
+            help="language of messages, default is detected from CCGO_LOCALE, LC_ALL or LANG",
+        )
         # parse only known args
         args, unknown = parser.parse_known_args()
+        sys.argv = sys.argv[:1] + strip_global_options(sys.argv[1:])
         return args
 
     def exec(self, context: CliContext, args: CliNameSpace):
-        print(vars(args))
+        setup_logging(args.log_level, args.log_file, args.log_format)
+        set_locale(args.locale or detect_locale())
+        if args.trace and args.subcommand != "trace":
+            start_trace()
+        get_logger().debug(f"command: {vars(args)}")
+        apply_net_env()
         # get module name
         module_name = f"commands.{args.subcommand}"
         # get class name
@@ -67,7 +150,42 @@ class Cli(CliCommand):
         # instance class
         sub_cmd = klass()
         # now execute the subcommand
-        sub_cmd.exec(CliContext(), sub_cmd.cli())
+        start_time = time.time()
+        sub_args = None
+        exit_code = ExitCode.SUCCESS
+        error_title = None
+        try:
+            sub_args = sub_cmd.cli()
+            sub_cmd.exec(CliContext(), sub_args)
+        except SystemExit as e:
+            # sys.exit(None) is success and sys.exit("message") is failure
+            if isinstance(e.code, int):
+                exit_code = e.code
+            elif e.code is not None:
+                exit_code = ExitCode.GENERAL_ERROR
+            # point failures to their explanation, the specific one if given
+            error_code = getattr(e, "error_code", None)
+            if error_code in SUBSYSTEM_ERROR_CODES:
+                error_title = SUBSYSTEM_ERROR_CODES[error_code]["title"]
+            elif exit_code in ERROR_CODES:
+                error_code = format_error_code(exit_code)
+                error_title = ERROR_CODES[exit_code]["title"]
+            if error_code and args.subcommand != "explain":
+                get_logger().error(tr("error[{code}]: {title}, run `ccgo explain {code}` for details",
+                                      code=error_code, title=tr(error_title)))
+            raise
+        except KeyboardInterrupt:
+            # 128 + SIGINT, like shells
+            exit_code = 130
+            raise
+        except Exception:
+            exit_code = ExitCode.GENERAL_ERROR
+            raise
+        finally:
+            if args.subcommand != "journal":
+                error = error_title or (ERROR_CODES.get(exit_code, {}).get("title") if exit_code else None)
+                record_invocation(args.subcommand, getattr(sub_args, "target", None),
+                                  time.time() - start_time, exit_code, error)
 
 
 def main():
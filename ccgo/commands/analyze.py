@@ -0,0 +1,220 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import sys
+import shlex
+import shutil
+import argparse
+import tempfile
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.cmd.cmd_util import exec_command
+from utils.compile_db.compile_db_util import find_compile_db
+from utils.compile_db.compile_db_util import load_compile_db
+from utils.compile_db.compile_db_util import get_entry_arguments
+from utils.analyze.report_util import parse_diagnostics
+from utils.analyze.report_util import format_report
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import exit_with_error
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+# "src/a.cpp should add these lines:" of include-what-you-use output
+IWYU_SECTION_PATTERN = re.compile(r"^(?P<file>.+) should (?P<action>add|remove) these lines:$")
+IWYU_FIX_SCRIPTS = ["fix_includes.py", "fix_include"]
+
+class Analyze(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to run static analysis over the compile database
+        and merge the results of clang-tidy, cppcheck and compiler warnings
+        into one report.
+        """
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            "--compile-db",
+            action="store",
+            help="compile_commands.json or its dir, default is the newest one in cmake_build",
+        )
+        parser.add_argument(
+            "--build-log",
+            action="store",
+            help="build log to collect compiler warnings from",
+        )
+        parser.add_argument(
+            "--format",
+            action="store",
+            default="text",
+            choices=["sarif", "json", "text"],
+            help="report format",
+        )
+        parser.add_argument(
+            "--output",
+            action="store",
+            help="report file, default is stdout",
+        )
+        parser.add_argument(
+            "--iwyu",
+            action="store_true",
+            help="run include-what-you-use and report the suggested include changes",
+        )
+        parser.add_argument(
+            "--fix",
+            action="store_true",
+            help="apply the include-what-you-use suggestions with fix_includes.py",
+        )
+        parser.add_argument(
+            "--exclude",
+            action="store",
+            default="third_party,cmake_build",
+            help="skip sources whose path contains any of these, use ',' to split them.",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def run_clang_tidy(self, compile_db_path, sources) -> str:
+        db_dir = os.path.dirname(os.path.abspath(compile_db_path))
+        outputs = []
+        for source in sources:
+            err_code, output = exec_command(f"clang-tidy -p \"{db_dir}\" --quiet \"{source}\"")
+            outputs.append(output)
+        return "\n".join(outputs)
+
+    def run_cppcheck(self, compile_db_path, excludes) -> str:
+        template = "{file}:{line}:{column}: {severity}: {message} [{id}]"
+        ignores = " ".join([f"-i\"{x}\"" for x in excludes])
+        cmd = f"cppcheck --project=\"{compile_db_path}\" --enable=warning,style,performance,portability " \
+              f"--template=\"{template}\" -q {ignores}"
+        err_code, output = exec_command(cmd)
+        return output
+
+    def run_iwyu(self, entries) -> str:
+        outputs = []
+        for entry in entries:
+            arguments = get_entry_arguments(entry)
+            # keep the flags, replace the compiler
+            arguments[0] = "include-what-you-use"
+            cmd = f"cd {shlex.quote(entry['directory'])} && " + " ".join([shlex.quote(x) for x in arguments])
+            # include-what-you-use always exits with non-zero code
+            err_code, output = exec_command(cmd)
+            outputs.append(output)
+        return "\n".join(outputs)
+
+    def summarize_iwyu(self, output) -> dict:
+        # {file: {"add": [...], "remove": [...]}}
+        summary = {}
+        current = None
+        for line in output.splitlines():
+            match = IWYU_SECTION_PATTERN.match(line.strip())
+            if match:
+                current = summary.setdefault(match.group("file"), {"add": [], "remove": []})[match.group("action")]
+                continue
+            if not line.strip() or line.startswith("The full include-list") or line.startswith("---"):
+                current = None
+                continue
+            if current is not None:
+                current.append(line.strip())
+        return summary
+
+    def exec_iwyu(self, args: CliNameSpace, entries):
+        if not shutil.which("include-what-you-use"):
+            logger.error("include-what-you-use is not installed")
+            exit_with_error("E0104")
+        output = self.run_iwyu(entries)
+        summary = self.summarize_iwyu(output)
+        changed = {k: v for k, v in summary.items() if v["add"] or v["remove"]}
+        for file, actions in sorted(changed.items()):
+            print(f"\n{file}: +{len(actions['add'])} -{len(actions['remove'])}")
+            for line in actions["add"]:
+                print(f"  + {line}")
+            for line in actions["remove"]:
+                print(f"  {line}")
+        print(f"\n{len(changed)} of {len(entries)} files have include suggestions")
+        if not args.fix or not changed:
+            return
+        fix_script = next((x for x in IWYU_FIX_SCRIPTS if shutil.which(x)), None)
+        if not fix_script:
+            logger.error("fix_includes.py of include-what-you-use is not found in PATH")
+            exit_with_error("E0104")
+        with tempfile.NamedTemporaryFile("w", suffix=".iwyu", delete=False) as f:
+            f.write(output)
+        try:
+            # the exit code of fix_includes.py is the number of edited files
+            err_code, fix_output = exec_command(f"{fix_script} --nosafe_headers < \"{f.name}\"")
+        finally:
+            os.remove(f.name)
+        print(fix_output)
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        diagnostics = []
+        tools = []
+        excludes = [x for x in args.exclude.split(",") if x]
+        compile_db_path = find_compile_db(args.compile_db)
+        if args.iwyu:
+            if not compile_db_path:
+                logger.error("No compile_commands.json found, use --compile-db")
+                sys.exit(ExitCode.CONFIG_ERROR)
+            entries = load_compile_db(compile_db_path)
+            entries = [x for x in entries if not any([e in x["file"] for e in excludes])]
+            self.exec_iwyu(args, entries)
+            return
+        if compile_db_path:
+            sources = [x["file"] for x in load_compile_db(compile_db_path)]
+            sources = [x for x in sources if not any([e in x for e in excludes])]
+            logger.info(f"Analyzing {len(sources)} sources of {compile_db_path}")
+            if shutil.which("clang-tidy"):
+                tools.append("clang-tidy")
+                diagnostics.extend(parse_diagnostics(
+                    "clang-tidy", self.run_clang_tidy(compile_db_path, sources)))
+            if shutil.which("cppcheck"):
+                tools.append("cppcheck")
+                diagnostics.extend(parse_diagnostics(
+                    "cppcheck", self.run_cppcheck(compile_db_path, excludes)))
+        elif not args.build_log:
+            logger.error("No compile_commands.json found, build with CMAKE_EXPORT_COMPILE_COMMANDS=ON "
+                  "or use --compile-db")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        if args.build_log:
+            tools.append("compiler")
+            with open(args.build_log, "r", errors="replace") as f:
+                diagnostics.extend(parse_diagnostics("compiler", f.read()))
+        if not tools:
+            logger.error("Neither clang-tidy nor cppcheck is installed")
+            exit_with_error("E0104")
+        report = format_report(diagnostics, tools, args.format)
+        if args.output:
+            with open(args.output, "w") as f:
+                f.write(report + "\n")
+            logger.info(f"Found {len(diagnostics)} issues by {tools}, report saved to {args.output}")
+        else:
+            print(report)
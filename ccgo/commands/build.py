@@ -11,6 +11,10 @@
 
 import os
 import sys
+import glob
+import time
+import shutil
+import zipfile
 import argparse
 import subprocess
 from copier import run_copy
@@ -27,6 +31,54 @@ from utils.context.namespace import CliNameSpace
 from utils.context.context import CliContext
 from utils.context.command import CliCommand
 from utils.cmd.cmd_util import exec_command
+from utils.cmd.cmd_util import exec_command_system
+from utils.cmd.cmd_util import exec_command_streaming
+from utils.env.env_util import load_env
+from utils.env.env_util import apply_env
+from utils.env.env_util import mask_env_args
+from utils.env.env_util import mask_env_value
+from utils.toolchain.xcode_util import list_installed_xcodes
+from utils.toolchain.xcode_util import find_xcode_developer_dir
+from utils.metrics.metrics_util import is_metrics_enabled
+from utils.metrics.metrics_util import record_build_metrics
+from utils.state.state_util import load_build_state
+from utils.state.state_util import save_build_state
+from utils.state.state_util import make_platform_state
+from utils.cache.cache_util import DEFAULT_BUILD_DIR
+from utils.cache.cache_util import parse_size
+from utils.cache.cache_util import get_cache_entries
+from utils.cache.cache_util import select_prune_entries
+from utils.cache.cache_util import remove_entries
+from utils.cache.cache_util import format_size
+from utils.toolchain.toolchain_config_util import TOOLCHAINS_CONFIG_PATH
+from utils.toolchain.toolchain_config_util import apply_toolchain_config
+from utils.toolchain.toolchain_config_util import load_toolchain_config
+from utils.toolchain.toolchain_config_util import get_custom_toolchains
+from utils.toolchain.toolchain_config_util import render_custom_toolchain_file
+from utils.toolchain.toolchain_config_util import get_custom_toolchain_file
+from utils.toolchain.toolchain_config_util import write_custom_toolchain_file
+from utils.hardening.hardening_util import HARDENING_FLAGS
+from utils.hardening.hardening_util import get_hardening_env
+from utils.hardening.hardening_util import write_fortify_header
+from utils.config.config_header_util import CONFIG_HEADER_NAME
+from utils.config.config_header_util import write_config_header
+from utils.config.config_header_util import get_config_header_dir
+from utils.config.config_header_util import get_archive_header_name
+from utils.config.config_header_util import remove_stale_cmake_caches
+from utils.package.package_util import is_sdk_archive_of
+from utils.package.package_util import find_build_info_name
+from utils.package.package_util import add_file_to_archive
+from utils.test.test_result_util import get_test_result_env
+from utils.test.test_result_util import clear_test_results
+from utils.test.test_result_util import load_test_results
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import exit_with_error
+from utils.log.log_util import get_logger
+from utils.log.log_util import has_log_file
+from utils.log.log_util import set_log_context
+from utils.i18n.i18n_util import tr
+
+logger = get_logger()
 
 class Build(CliCommand):
     def description(self) -> str:
@@ -38,9 +90,18 @@ class Build(CliCommand):
         return [
             "android", "ios", "windows",
             "linux", "macos",
-            "tests", "benches"
+            "tests", "benches",
+            "all"
         ]
 
+    def get_host_platform_list(self) -> list:
+        # platforms `build all` can build on this host
+        if sys.platform == "darwin":
+            return ["android", "ios", "macos"]
+        if sys.platform == "win32":
+            return ["android", "windows"]
+        return ["android", "linux"]
+
     def cli(self) -> CliNameSpace:
         parser = argparse.ArgumentParser(
             # 获取文件名
@@ -65,19 +126,417 @@ class Build(CliCommand):
             default="armeabi-v7a,arm64-v8a,x86_64",
             help="arch like armeabi-v7a,arm64-v8a,x86_64, etc, if choose more than one, use ',' to split them.",
         )
+        parser.add_argument(
+            "--toolchain-file",
+            action="store",
+            help="cmake toolchain file used to cross compile the library, like my.toolchain.cmake",
+        )
+        parser.add_argument(
+            "--toolchain",
+            action="store",
+            help="custom cross toolchain registered as [toolchains.<name>] in .ccgo/toolchains.toml",
+        )
+        parser.add_argument(
+            "--xcode",
+            action="store",
+            help="xcode version used to build ios/macos, like 15.2, default is DEVELOPER_DIR, "
+                 "the [xcode] version pinned in .ccgo/toolchains.toml or xcode-select",
+        )
+        parser.add_argument(
+            "--hardening",
+            action="store_true",
+            help="compile with stack protector, fortify source, relro, nx and cfg flags of the platform, "
+                 "see `ccgo inspect hardening` to check the binaries",
+        )
+        parser.add_argument(
+            "--config-header",
+            action="store_true",
+            help="generate ccgo_config.h of version, git revision, platform and --feature macros into the include path "
+            "and the sdk archives, the cmake caches configured without it are configured again",
+        )
+        parser.add_argument(
+            "--feature",
+            action="append",
+            default=[],
+            help="feature defined as CCGO_FEATURE_<NAME> in ccgo_config.h, can be repeated",
+        )
+        parser.add_argument(
+            "--metrics",
+            action="store_true",
+            help="record build duration and result to .ccgo/build_metrics.jsonl, see `ccgo stats`",
+        )
+        parser.add_argument(
+            "--distributed",
+            action="store",
+            choices=["auto", "icecc", "distcc"],
+            help="compile through icecream or distcc farm, auto uses the first one found",
+        )
+        parser.add_argument(
+            "--jobs",
+            action="store",
+            type=int,
+            help="parallel compile jobs, default of distributed build is sized for the farm",
+        )
+        parser.add_argument(
+            "--platforms",
+            action="store",
+            help="platforms of `build all`, default is all platforms the host can build, use ',' to split them.",
+        )
+        parser.add_argument(
+            "--resume",
+            action="store_true",
+            help="skip platforms which succeeded in the last `build all`",
+        )
+        parser.add_argument(
+            "-k", "--keep-going",
+            action="store_true",
+            help="continue `build all` with the other platforms when one fails",
+        )
+        parser.add_argument(
+            "--filter",
+            action="store",
+            help="only run tests whose full name contains it, like Net or MySuite.MyCase, "
+                 "use ',' to split more than one, '*' and '?' are wildcards",
+        )
+        parser.add_argument(
+            "--exact",
+            action="store_true",
+            help="match the full test name of --filter exactly instead of by substring",
+        )
+        parser.add_argument(
+            "--list",
+            action="store_true",
+            help="list the tests instead of running them",
+        )
+        parser.add_argument(
+            "--env",
+            action="append",
+            default=[],
+            help="environment variable KEY=VALUE passed to the build tools, can be repeated",
+        )
+        parser.add_argument(
+            "--env-file",
+            action="store",
+            help="dotenv file of environment variables passed to the build tools, like .env",
+        )
+        parser.add_argument(
+            "--dry-run",
+            action="store_true",
+            help="print the toolchains, environment, archs, commands and artifacts of the build without running it",
+        )
         module_name = os.path.splitext(os.path.basename(__file__))[0]
         input_argv = [x for x in sys.argv[1:] if x != module_name]
         args, unknown = parser.parse_known_args(input_argv)
         return args
 
-    def exec(self, context: CliContext, args: CliNameSpace):
-        print("Building library, with configuration...")
-        print(vars(args))
+    def get_xcode_version(self, args: CliNameSpace, base_env) -> str:
+        # --xcode wins over the [xcode] pin of .ccgo/toolchains.toml, the pin
+        # is left alone when the user set DEVELOPER_DIR
+        if args.xcode:
+            return args.xcode
+        if base_env.get("DEVELOPER_DIR"):
+            return None
+        try:
+            return load_toolchain_config().get("xcode", {}).get("version")
+        except (OSError, ValueError):
+            # warned when the pins were applied
+            return None
+
+    def setup_custom_toolchain(self, args: CliNameSpace):
+        if args.toolchain_file:
+            logger.error(tr("--toolchain and --toolchain-file can not be used together"))
+            sys.exit(ExitCode.USAGE_ERROR)
+        try:
+            toolchains = get_custom_toolchains(load_toolchain_config())
+            toolchain = toolchains.get(args.toolchain)
+            if toolchain is None:
+                logger.error(tr("Toolchain {name} not found in {path}, registered: {names}",
+                                name=args.toolchain, path=TOOLCHAINS_CONFIG_PATH, names=sorted(toolchains)))
+                exit_with_error("E0302")
+            if args.dry_run:
+                render_custom_toolchain_file(args.toolchain, toolchain)
+                toolchain_file = get_custom_toolchain_file(args.toolchain)
+            else:
+                toolchain_file = write_custom_toolchain_file(args.toolchain, toolchain)
+        except (OSError, ValueError) as e:
+            logger.error(f"{TOOLCHAINS_CONFIG_PATH}: {e}")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        # same as --toolchain-file, cmake(>=3.21) picks it up from environment
+        os.environ["CMAKE_TOOLCHAIN_FILE"] = toolchain_file
+        logger.info(tr("Using toolchain {name} of {path}", name=args.toolchain, path=TOOLCHAINS_CONFIG_PATH))
+
+    def setup_distributed(self, args: CliNameSpace):
+        launcher = args.distributed
+        if launcher == "auto":
+            launcher = next((x for x in ["icecc", "distcc"] if shutil.which(x)), None)
+        if not launcher or not shutil.which(launcher):
+            logger.error(tr("Distributed compiler {launcher} not found in PATH", launcher=launcher or "icecc or distcc"))
+            exit_with_error("E0104")
+        jobs = args.jobs
+        if not jobs and launcher == "distcc":
+            # sum of the job limits of DISTCC_HOSTS
+            err_code, output = exec_command("distcc -j")
+            jobs = int(output.strip()) if err_code == 0 and output.strip().isdigit() else None
+        if not jobs:
+            # icecream schedules jobs over the farm, keep it busy
+            jobs = (os.cpu_count() or 1) * 4
+        # cmake(>=3.17) reads the launchers from environment
+        os.environ["CMAKE_C_COMPILER_LAUNCHER"] = launcher
+        os.environ["CMAKE_CXX_COMPILER_LAUNCHER"] = launcher
+        os.environ["CMAKE_BUILD_PARALLEL_LEVEL"] = str(jobs)
+        logger.info(tr("Compiling through {launcher} with {jobs} jobs", launcher=launcher, jobs=jobs))
+
+    def setup_env(self, args: CliNameSpace):
+        try:
+            env = load_env(args.env_file, args.env)
+        except (OSError, ValueError) as e:
+            logger.error(tr("Failed to load environment: {error}", error=e))
+            sys.exit(ExitCode.CONFIG_ERROR)
+        if env:
+            logger.info(tr("Environment:"))
+            apply_env(env)
+
+    def get_gtest_filter(self, test_filter, exact) -> str:
+        # "Net,MySuite.MyCase" -> "*Net*:*MySuite.MyCase*"
+        patterns = []
+        for pattern in [x.strip() for x in test_filter.split(",") if x.strip()]:
+            if not exact and "*" not in pattern and "?" not in pattern:
+                pattern = f"*{pattern}*"
+            patterns.append(pattern)
+        return ":".join(patterns)
+
+    def setup_test_selection(self, args: CliNameSpace):
+        # googletest reads its flags from environment of the test executables
+        if args.filter:
+            os.environ["GTEST_FILTER"] = self.get_gtest_filter(args.filter, args.exact)
+            logger.info(tr("Test filter: {filter}", filter=os.environ["GTEST_FILTER"]))
+        if args.list:
+            os.environ["GTEST_LIST_TESTS"] = "1"
+
+    def prune_cache(self, platforms):
+        # evict the least recently used build trees over CCGO_CACHE_MAX_SIZE,
+        # but never the ones just built
+        max_size = os.environ.get("CCGO_CACHE_MAX_SIZE")
+        if not max_size:
+            return
+        try:
+            max_size = parse_size(max_size)
+        except ValueError as e:
+            logger.warning(f"Ignore CCGO_CACHE_MAX_SIZE: {e}")
+            return
+        keep = [os.path.join(DEFAULT_BUILD_DIR, x) for x in platforms]
+        selected = select_prune_entries(get_cache_entries(), max_size=max_size, keep=keep)
+        if selected:
+            freed = remove_entries(selected)
+            logger.info(f"Pruned {[x['path'] for x in selected]} over CCGO_CACHE_MAX_SIZE, {format_size(freed)} freed")
+
+    def setup_hardening(self, args: CliNameSpace, platform) -> dict:
+        env = get_hardening_env(platform, os.environ)
+        if HARDENING_FLAGS.get(platform, {}).get("fortify") and not args.dry_run:
+            write_fortify_header(platform)
+        logger.info(tr("Hardening flags of {platform}:", platform=platform))
+        for name, value in env.items():
+            logger.info(f"  {name}={value}")
+        for reason in HARDENING_FLAGS.get(platform, {}).get("skipped", []):
+            logger.info(tr("  not applied, {reason}", reason=reason))
+        return env
+
+    def get_platform_env(self, args: CliNameSpace, platform) -> dict:
+        env = self.setup_hardening(args, platform) if args.hardening else {}
+        if args.config_header:
+            if args.dry_run:
+                include_dir = get_config_header_dir(platform)
+            else:
+                include_dir = write_config_header(platform, args.feature)
+                logger.info(tr("Generated {path}", path=os.path.join(include_dir, CONFIG_HEADER_NAME)))
+                for path in remove_stale_cmake_caches(os.path.join(DEFAULT_BUILD_DIR, platform), include_dir):
+                    logger.info(tr("Removed {path} to configure again with the include dir", path=path))
+            for name in ["CFLAGS", "CXXFLAGS"]:
+                flags = env.get(name, os.environ.get(name))
+                env[name] = " ".join([x for x in [flags, f"-I\"{include_dir}\""] if x])
+        if platform == "tests":
+            env.update(get_test_result_env())
+        return env
+
+    def embed_config_header(self, platform, since):
+        # the sdk archives made by this build get the header of their macros
+        header_path = os.path.join(get_config_header_dir(platform), CONFIG_HEADER_NAME)
+        with open(header_path, "r") as f:
+            content = f.read()
+        for archive in sorted(glob.glob(os.path.join("bin", "*.zip"))):
+            if not is_sdk_archive_of(os.path.basename(archive), platform) or os.path.getmtime(archive) < since:
+                continue
+            with zipfile.ZipFile(archive) as f:
+                name = get_archive_header_name(find_build_info_name(f.namelist()))
+            add_file_to_archive(archive, name, content)
+            logger.info(tr("Embedded {name} into {archive}", name=name, archive=archive))
+
+    def get_platform_command(self, args: CliNameSpace, platform) -> str:
         num = 2 if args.ide_project else 1
-        arch = args.arch if args.target == "android" else ""
-        cmd = f"python3 build_{args.target}.py {num} {arch.replace(',', ' ')}"
-        print("\nExecute command:")
-        print(cmd)
-        err_code = os.system(cmd)
-        sys.exit(err_code)
+        arch = args.arch if platform == "android" else ""
+        script = f"build_{platform}.py"
+        if not os.path.isfile(script):
+            logger.error(tr("{script} not found, run ccgo build in the project dir created by `ccgo lib create`", script=script))
+            exit_with_error("E0301")
+        return f"python3 {script} {num} {arch.replace(',', ' ')}".strip()
+
+    def get_toolchain_desc(self, platform) -> str:
+        if platform == "android":
+            ndk = os.environ.get("ANDROID_NDK_HOME")
+            return f"ndk {ndk}" if ndk else "ndk of the gradle project in ANDROID_HOME"
+        if platform in ["ios", "macos"]:
+            return f"xcode {os.environ.get('DEVELOPER_DIR') or 'of xcode-select'}"
+        if os.environ.get("CMAKE_TOOLCHAIN_FILE"):
+            return f"cmake toolchain file {os.environ['CMAKE_TOOLCHAIN_FILE']}"
+        return "host compiler"
+
+    def print_plan(self, args: CliNameSpace, platforms, base_env):
+        # env set by ccgo on top of the environment it was started with
+        env = {k: v for k, v in os.environ.items() if base_env.get(k) != v}
+        print(tr("Build plan:"))
+        for platform in platforms:
+            cmd = self.get_platform_command(args, platform)
+            platform_env = {**env, **self.get_platform_env(args, platform)}
+            print(f"\n==== {platform} ====")
+            print(tr("  toolchain:   {toolchain}", toolchain=self.get_toolchain_desc(platform)))
+            archs = args.arch if platform == "android" else tr("default of build_{platform}.py", platform=platform)
+            print(tr("  archs:       {archs}", archs=archs))
+            print(tr("  command:     {cmd}", cmd=cmd))
+            print(tr("  build dir:   {path}", path=os.path.join(DEFAULT_BUILD_DIR, platform)))
+            if args.config_header:
+                print(tr("  generates:   {path}", path=os.path.join(get_config_header_dir(platform), CONFIG_HEADER_NAME)))
+            print(tr("  environment:"))
+            for name in sorted(platform_env):
+                print(f"    {name}={mask_env_value(name, platform_env[name])}")
+            if not platform_env:
+                print(tr("    (unchanged)"))
+        print("\n" + tr("Nothing was built, run again without --dry-run to build"))
+
+    def build_platform(self, args: CliNameSpace, platform) -> int:
+        arch = args.arch if platform == "android" else ""
+        cmd = self.get_platform_command(args, platform)
+        platform_env = self.get_platform_env(args, platform)
+        saved_env = {x: os.environ.get(x) for x in platform_env}
+        os.environ.update(platform_env)
+        logger.info(tr("Execute command: {cmd}", cmd=cmd))
+        if platform == "tests":
+            clear_test_results()
+        start_time = time.time()
+        try:
+            if has_log_file():
+                # tee the output of the build script into the log file
+                err_code = exec_command_streaming(cmd)
+            else:
+                err_code = exec_command_system(cmd)
+        finally:
+            # each platform of `build all` gets its own flags
+            for name, value in saved_env.items():
+                if value is None:
+                    os.environ.pop(name, None)
+                else:
+                    os.environ[name] = value
+        if err_code == 0 and args.config_header:
+            self.embed_config_header(platform, start_time)
+        if is_metrics_enabled(args.metrics):
+            record_build_metrics(platform, arch, time.time() - start_time, err_code)
+        return err_code
 
+    def get_failure_exit_code(self, platform) -> int:
+        # tests which ran and failed are told apart from the compile errors
+        if platform != "tests":
+            return ExitCode.COMPILE_ERROR
+        ran, failures = load_test_results()
+        if not ran:
+            return ExitCode.COMPILE_ERROR
+        if failures:
+            logger.error(tr("{count} tests failed: {names}", count=len(failures), names=", ".join(failures)))
+        return ExitCode.TEST_FAILURE
+
+    def build_all(self, args: CliNameSpace, platforms):
+        last_state = load_build_state() if args.resume else {}
+        state = dict(last_state)
+        failed = []
+        for platform in platforms:
+            if last_state.get(platform, {}).get("success"):
+                logger.info(tr("Skip {platform}, it succeeded in the last build", platform=platform))
+                continue
+            set_log_context(platform=platform)
+            logger.info(tr("==== Building {platform} ====", platform=platform))
+            err_code = self.build_platform(args, platform)
+            set_log_context(platform=None)
+            state[platform] = make_platform_state(err_code)
+            save_build_state(state)
+            if err_code != 0:
+                failed.append(platform)
+                if not args.keep_going:
+                    break
+        print("\n" + tr("Build summary:"))
+        for platform in platforms:
+            result = state.get(platform)
+            status = "not built" if not result else "ok" if result["success"] else "failed"
+            print(f"  {platform:<10} {tr(status)}")
+        if failed:
+            logger.error(tr("Retry the failed platforms with `ccgo build all --resume`"))
+            exit_codes = [self.get_failure_exit_code(x) for x in failed]
+            # a compile error of any platform wins over failed tests
+            exit_with_error("E0303" if ExitCode.COMPILE_ERROR in exit_codes else "E0401")
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        logger.info(tr("Building library..."))
+        logger.debug(f"configuration: { {**vars(args), 'env': mask_env_args(args.env)} }")
+        base_env = dict(os.environ)
+        apply_toolchain_config()
+        self.setup_env(args)
+        if args.target == "all":
+            platforms = args.platforms.split(",") if args.platforms else self.get_host_platform_list()
+            unknown_platforms = [x for x in platforms if x not in self.get_target_list() or x == "all"]
+            if unknown_platforms:
+                logger.error(tr("Unsupported platforms: {platforms}", platforms=unknown_platforms))
+                sys.exit(ExitCode.USAGE_ERROR)
+        else:
+            platforms = [args.target]
+        if args.toolchain_file:
+            toolchain_file = os.path.abspath(args.toolchain_file)
+            if not os.path.isfile(toolchain_file):
+                logger.error(tr("Toolchain file not found: {path}", path=toolchain_file))
+                sys.exit(ExitCode.CONFIG_ERROR)
+            # cmake(>=3.21) picks up the toolchain file from environment
+            os.environ["CMAKE_TOOLCHAIN_FILE"] = toolchain_file
+        if args.toolchain:
+            self.setup_custom_toolchain(args)
+        xcode = self.get_xcode_version(args, base_env)
+        if xcode and any([x in ["ios", "macos"] for x in platforms]):
+            developer_dir = find_xcode_developer_dir(xcode)
+            if not developer_dir:
+                xcodes = list_installed_xcodes()
+                available = "\n".join([f"  {version} ({path})" for version, path in xcodes]) or "  (none)"
+                logger.error(tr("Xcode {version} not found, available versions:\n{available}", version=xcode, available=available))
+                exit_with_error("E0102")
+            # xcodebuild, lipo and xcrun all follow DEVELOPER_DIR
+            os.environ["DEVELOPER_DIR"] = developer_dir
+            logger.info(tr("Using Xcode {version} at {path}", version=xcode, path=developer_dir))
+        if args.feature and not args.config_header:
+            logger.error(tr("--feature only works with --config-header"))
+            sys.exit(ExitCode.USAGE_ERROR)
+        if args.filter or args.list:
+            if "tests" not in platforms:
+                logger.error(tr("--filter and --list only work with `ccgo build tests`"))
+                sys.exit(ExitCode.USAGE_ERROR)
+            self.setup_test_selection(args)
+        if args.distributed:
+            self.setup_distributed(args)
+        elif args.jobs:
+            os.environ["CMAKE_BUILD_PARALLEL_LEVEL"] = str(args.jobs)
+        if args.dry_run:
+            self.print_plan(args, platforms, base_env)
+            return
+        if args.target == "all":
+            self.build_all(args, platforms)
+            self.prune_cache(platforms)
+            return
+        set_log_context(platform=args.target)
+        err_code = self.build_platform(args, args.target)
+        self.prune_cache(platforms)
+        if err_code != 0:
+            logger.error(tr("Build {platform} failed with exit code {err_code}", platform=args.target, err_code=err_code))
+            sys.exit(self.get_failure_exit_code(args.target))
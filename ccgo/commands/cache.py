@@ -0,0 +1,135 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import time
+import json
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.cache.cache_util import DEFAULT_BUILD_DIR
+from utils.cache.cache_util import parse_size
+from utils.cache.cache_util import parse_age
+from utils.cache.cache_util import get_cache_entries
+from utils.cache.cache_util import select_prune_entries
+from utils.cache.cache_util import remove_entries
+from utils.cache.cache_util import format_size
+from utils.error.exit_code import ExitCode
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+class Cache(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to show and prune the disk usage of build trees and fetched sdks.
+        """
+
+    def get_target_list(self) -> list:
+        return ["stats", "prune"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            "--build-dir",
+            action="store",
+            default=DEFAULT_BUILD_DIR,
+            help="dir of the build trees",
+        )
+        parser.add_argument(
+            "--older-than",
+            action="store",
+            help="prune caches unused for this long, like 30d or 12h",
+        )
+        parser.add_argument(
+            "--max-size",
+            action="store",
+            default=os.environ.get("CCGO_CACHE_MAX_SIZE"),
+            help="prune the least recently used caches until the total is under it, "
+                 "like 50GB, default is CCGO_CACHE_MAX_SIZE",
+        )
+        parser.add_argument(
+            "--dry-run",
+            action="store_true",
+            help="only show the caches to prune",
+        )
+        parser.add_argument(
+            "--format",
+            action="store",
+            default="text",
+            choices=["text", "json"],
+            help="format of stats",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def stats(self, args: CliNameSpace, entries):
+        if args.format == "json":
+            print(json.dumps(entries, indent=2))
+            return
+        if not entries:
+            print(f"No caches found in {args.build_dir} or .ccgo/sdk")
+            return
+        now = time.time()
+        print(f"{'path':<40} {'kind':<6} {'size':>10} {'unused(d)':>10}")
+        for entry in sorted(entries, key=lambda x: x["size"], reverse=True):
+            unused_days = (now - entry["last_used"]) / 86400
+            print(f"{entry['path']:<40} {entry['kind']:<6} {format_size(entry['size']):>10} {unused_days:>10.1f}")
+        print(f"\nTotal {format_size(sum([x['size'] for x in entries]))} in {len(entries)} caches")
+
+    def prune(self, args: CliNameSpace, entries):
+        if not args.older_than and not args.max_size:
+            logger.error("Use --older-than or --max-size to select the caches to prune")
+            sys.exit(ExitCode.USAGE_ERROR)
+        try:
+            older_than = parse_age(args.older_than) if args.older_than else None
+            max_size = parse_size(args.max_size) if args.max_size else None
+        except ValueError as e:
+            logger.error(str(e))
+            sys.exit(ExitCode.USAGE_ERROR)
+        selected = select_prune_entries(entries, older_than, max_size)
+        for entry in selected:
+            print(f"{'would remove' if args.dry_run else 'remove'} {entry['path']} ({format_size(entry['size'])})")
+        if args.dry_run:
+            return
+        freed = remove_entries(selected)
+        logger.info(f"Pruned {len(selected)} caches, {format_size(freed)} freed")
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        entries = get_cache_entries(args.build_dir)
+        if args.target == "stats":
+            self.stats(args, entries)
+        else:
+            self.prune(args, entries)
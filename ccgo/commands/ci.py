@@ -0,0 +1,196 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.error.exit_code import ExitCode
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+# runner of github actions and tag of gitlab runner for each platform
+PLATFORM_RUNNERS = {
+    "android": ("ubuntu-latest", "linux"),
+    "linux": ("ubuntu-latest", "linux"),
+    "ios": ("macos-latest", "macos"),
+    "macos": ("macos-latest", "macos"),
+    "windows": ("windows-latest", "windows"),
+}
+
+# the sdk zips of the build, like the assets of `ccgo publish github-release`
+DEFAULT_ARTIFACT_PATH = "bin/*.zip"
+
+GITHUB_WORKFLOW_TEMPLATE = """name: ccgo
+
+on:
+  push:
+    branches: [ main, master ]
+    tags: [ 'v*' ]
+  pull_request:
+
+jobs:
+  build:
+    strategy:
+      fail-fast: false
+      matrix:
+        include:
+{matrix}
+    runs-on: ${{{{ matrix.runner }}}}
+    defaults:
+      run:
+        working-directory: {project_dir}
+    steps:
+      - uses: actions/checkout@v4
+      - uses: actions/setup-python@v5
+        with:
+          python-version: '3.x'
+      - run: pip3 install ccgo
+      - uses: actions/cache@v4
+        with:
+          path: {project_dir}/cmake_build
+          key: cmake-build-${{{{ matrix.platform }}}}-${{{{ hashFiles('{project_dir}/**/CMakeLists.txt') }}}}
+          restore-keys: cmake-build-${{{{ matrix.platform }}}}-
+      - run: ccgo build ${{{{ matrix.platform }}}}
+      - uses: actions/upload-artifact@v4
+        with:
+          name: ${{{{ matrix.platform }}}}
+          path: {project_dir}/{artifact_path}
+"""
+
+GITHUB_MATRIX_ITEM_TEMPLATE = """          - platform: {platform}
+            runner: {runner}"""
+
+GITLAB_JOB_TEMPLATE = """build-{platform}:
+  stage: build
+  tags: [ {tag} ]
+  script:
+    - cd {project_dir}
+    - pip3 install ccgo
+    - ccgo build {platform}
+  cache:
+    key: cmake-build-{platform}
+    paths:
+      - {project_dir}/cmake_build
+  artifacts:
+    paths:
+      - {project_dir}/{artifact_path}
+"""
+
+class Ci(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to generate the CI pipeline config.
+        """
+
+    def get_target_list(self) -> list:
+        return ["init"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            "--provider",
+            action="store",
+            default="github",
+            choices=["github", "gitlab"],
+            help="CI provider, github actions or gitlab ci",
+        )
+        parser.add_argument(
+            "--platforms",
+            action="store",
+            default="android,ios,macos,windows,linux",
+            help="platforms to build, if choose more than one, use ',' to split them.",
+        )
+        parser.add_argument(
+            "--project-dir",
+            action="store",
+            default=".",
+            help="directory to run ccgo build in, relative to the repository root",
+        )
+        parser.add_argument(
+            "--artifact-path",
+            action="store",
+            default=DEFAULT_ARTIFACT_PATH,
+            help=f"build output path or glob to upload, relative to the project dir, default is {DEFAULT_ARTIFACT_PATH}",
+        )
+        parser.add_argument(
+            "--force",
+            action="store_true",
+            help="overwrite the existing pipeline config",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def render_github(self, platforms, project_dir, artifact_path) -> str:
+        matrix = "\n".join([
+            GITHUB_MATRIX_ITEM_TEMPLATE.format(
+                platform=platform, runner=PLATFORM_RUNNERS[platform][0])
+            for platform in platforms
+        ])
+        return GITHUB_WORKFLOW_TEMPLATE.format(
+            matrix=matrix, project_dir=project_dir, artifact_path=artifact_path)
+
+    def render_gitlab(self, platforms, project_dir, artifact_path) -> str:
+        jobs = [
+            GITLAB_JOB_TEMPLATE.format(
+                platform=platform, tag=PLATFORM_RUNNERS[platform][1],
+                project_dir=project_dir, artifact_path=artifact_path)
+            for platform in platforms
+        ]
+        return "stages:\n  - build\n\n" + "\n".join(jobs)
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        logger.info("Generating CI pipeline config...")
+        logger.debug(f"configuration: {vars(args)}")
+        platforms = [x for x in args.platforms.split(",") if x]
+        unknown_platforms = [x for x in platforms if x not in PLATFORM_RUNNERS]
+        if not platforms or unknown_platforms:
+            logger.error(f"Unsupported platforms: {unknown_platforms}, choose from {list(PLATFORM_RUNNERS)}")
+            sys.exit(ExitCode.USAGE_ERROR)
+        if args.provider == "github":
+            config_path = os.path.join(".github", "workflows", "ccgo.yml")
+            content = self.render_github(platforms, args.project_dir, args.artifact_path)
+        else:
+            config_path = ".gitlab-ci.yml"
+            content = self.render_gitlab(platforms, args.project_dir, args.artifact_path)
+        if os.path.exists(config_path) and not args.force:
+            logger.error(f"{config_path} already exists, use --force to overwrite it")
+            sys.exit(ExitCode.USAGE_ERROR)
+        if os.path.dirname(config_path):
+            os.makedirs(os.path.dirname(config_path), exist_ok=True)
+        with open(config_path, "w") as f:
+            f.write(content)
+        logger.info(f"Generated {config_path}")
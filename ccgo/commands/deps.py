@@ -0,0 +1,116 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import json
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.compile_db.compile_db_util import find_compile_db
+from utils.compile_db.compile_db_util import load_compile_db
+from utils.compile_db.compile_db_util import get_entry_output
+from utils.deps.header_deps_util import build_header_graph
+from utils.error.exit_code import ExitCode
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+class Deps(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to analyze the header dependencies of the sources.
+        """
+
+    def get_target_list(self) -> list:
+        return ["headers"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            "--touch",
+            action="store",
+            help="header to report the translation units and objects rebuilt by its change",
+        )
+        parser.add_argument(
+            "--top",
+            action="store",
+            type=int,
+            default=20,
+            help="number of the most included headers to show",
+        )
+        parser.add_argument(
+            "--compile-db",
+            action="store",
+            help="compile_commands.json or its dir, default is the newest one in cmake_build",
+        )
+        parser.add_argument(
+            "--format",
+            action="store",
+            default="text",
+            choices=["text", "json"],
+            help="report format",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        compile_db_path = find_compile_db(args.compile_db)
+        if not compile_db_path:
+            logger.error("No compile_commands.json found, build with CMAKE_EXPORT_COMPILE_COMMANDS=ON "
+                  "or use --compile-db")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        entries = load_compile_db(compile_db_path)
+        graph = build_header_graph(entries, os.getcwd())
+        if args.touch:
+            header = os.path.normpath(os.path.abspath(args.touch))
+            sources = sorted(set(graph.get(header, [])))
+            outputs = {x["file"]: get_entry_output(x) for x in entries}
+            objects = [outputs.get(x) for x in sources]
+            if args.format == "json":
+                print(json.dumps({"header": header, "sources": sources,
+                                  "objects": [x for x in objects if x]}, indent=2))
+                return
+            print(f"Touching {os.path.relpath(header)} rebuilds {len(sources)} of {len(entries)} translation units:")
+            for source, obj in zip(sources, objects):
+                print(f"  {os.path.relpath(source)}" + (f" -> {os.path.relpath(obj)}" if obj else ""))
+            return
+        ranking = sorted(graph.items(), key=lambda x: (-len(set(x[1])), x[0]))
+        if args.format == "json":
+            print(json.dumps({os.path.relpath(k): len(set(v)) for k, v in ranking}, indent=2))
+            return
+        print(f"{'translation units':>18}  header")
+        for header, sources in ranking[:args.top]:
+            print(f"{len(set(sources)):>18}  {os.path.relpath(header)}")
+        print(f"\n{len(graph)} project headers included by {len(entries)} translation units")
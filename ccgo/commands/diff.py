@@ -0,0 +1,115 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import json
+import zipfile
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.package.diff_util import diff_snapshots
+from utils.cache.cache_util import format_size
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import exit_with_error
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+class Diff(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to compare two sdk archives or build_info.json files.
+        """
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'old',
+            help="old sdk zip or build_info.json",
+        )
+        parser.add_argument(
+            'new',
+            help="new sdk zip or build_info.json",
+        )
+        parser.add_argument(
+            "--nm",
+            action="store",
+            default="nm",
+            help="nm used to list the symbols of shared libs, like llvm-nm",
+        )
+        parser.add_argument(
+            "--format",
+            action="store",
+            default="text",
+            choices=["text", "json"],
+            help="report format",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def print_report(self, report):
+        print("\nBuild info:")
+        for item in report["build_info"]:
+            print(f"  {item['key']}: {item['old']} -> {item['new']}")
+        if not report["build_info"]:
+            print("  (unchanged)")
+        files = report["files"]
+        print(f"\nFiles: {len(files['added'])} added, {len(files['removed'])} removed, {len(files['changed'])} changed")
+        for item in files["added"]:
+            print(f"  + {item['name']} ({format_size(item['size'])})")
+        for item in files["removed"]:
+            print(f"  - {item['name']} ({format_size(item['size'])})")
+        for item in files["changed"]:
+            print(f"  ~ {item['name']} ({format_size(item['old_size'])} -> {format_size(item['new_size'])})")
+        if report["libs"]:
+            print("\nLib sizes:")
+            for item in report["libs"]:
+                sign = "+" if item["delta"] > 0 else "-"
+                print(f"  {item['name']}: {format_size(item['old_size'])} -> "
+                      f"{format_size(item['new_size'])} ({sign}{format_size(abs(item['delta']))})")
+        for item in report["symbols"]:
+            print(f"\nSymbols of {item['name']}: {len(item['added'])} added, {len(item['removed'])} removed")
+            for symbol in item["added"]:
+                print(f"  + {symbol}")
+            for symbol in item["removed"]:
+                print(f"  - {symbol}")
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        try:
+            report = diff_snapshots(args.old, args.new, args.nm)
+        except (OSError, zipfile.BadZipFile, ValueError) as e:
+            logger.error(f"Failed to compare {args.old} and {args.new}: {e}")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        except RuntimeError as e:
+            logger.error(f"{e}, pass the nm of the target toolchain with --nm")
+            exit_with_error("E0103")
+        if args.format == "json":
+            print(json.dumps(report, indent=2))
+        else:
+            self.print_report(report)
@@ -0,0 +1,210 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import json
+import shlex
+import argparse
+import tempfile
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.doc.doc_coverage_util import compute_coverage
+from utils.doc.doc_coverage_util import find_headers
+from utils.doc.doc_test_util import extract_markdown_snippets
+from utils.doc.doc_test_util import extract_header_snippets
+from utils.cmd.cmd_util import exec_command
+from utils.error.exit_code import ExitCode
+from utils.log.log_util import get_logger
+from utils.i18n.i18n_util import tr
+
+logger = get_logger()
+
+class Doc(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to check the doc comments of public headers,
+        and compile the c++ snippets of markdown files and doc comments.
+        """
+
+    def get_target_list(self) -> list:
+        return ["coverage", "test"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            "--include-dir",
+            action="store",
+            default="include",
+            help="dir of the public headers",
+        )
+        parser.add_argument(
+            "--threshold",
+            action="store",
+            type=float,
+            default=0,
+            help="fail if the coverage percentage is lower than it, like 80",
+        )
+        parser.add_argument(
+            "--list-missing",
+            action="store_true",
+            help="list the public symbols without doc comments",
+        )
+        parser.add_argument(
+            "--files",
+            action="store",
+            default="README.md",
+            help="markdown files to test the snippets of, use ',' to split them.",
+        )
+        parser.add_argument(
+            "--cxx",
+            action="store",
+            default=os.environ.get("CXX", "c++"),
+            help="compiler of the snippets, default is CXX or c++",
+        )
+        parser.add_argument(
+            "--cxxflags",
+            action="store",
+            default="-std=c++17",
+            help="extra flags to compile the snippets",
+        )
+        parser.add_argument(
+            "--prelude",
+            action="append",
+            default=[],
+            help="line prepended to every snippet, like '#include <foo/foo.h>', can be repeated",
+        )
+        parser.add_argument(
+            "--link",
+            action="append",
+            default=[],
+            help="library linked to the runnable snippets, like cmake_build/linux/libfoo.a, can be repeated",
+        )
+        parser.add_argument(
+            "--format",
+            action="store",
+            default="text",
+            choices=["text", "json"],
+            help="report format",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def percent(self, documented, total) -> float:
+        return documented * 100.0 / total if total else 100.0
+
+    def test_snippet(self, args: CliNameSpace, snippet, work_dir):
+        # return None if passed, otherwise the error output
+        source = os.path.join(work_dir, "snippet.cc")
+        prelude = "".join([x + "\n" for x in args.prelude])
+        with open(source, "w") as f:
+            # keep the compiler diagnostics pointing to the original file
+            f.write(f"{prelude}#line {snippet['line']} \"{os.path.abspath(snippet['file'])}\"\n{snippet['code']}")
+        cxx = f"{args.cxx} {args.cxxflags} -I{shlex.quote(args.include_dir)}"
+        if "run" not in snippet["flags"]:
+            err_code, output = exec_command(f"{cxx} -fsyntax-only {shlex.quote(source)}")
+            return output if err_code != 0 else None
+        executable = os.path.join(work_dir, "snippet")
+        libs = " ".join([shlex.quote(x) for x in args.link])
+        err_code, output = exec_command(f"{cxx} {shlex.quote(source)} {libs} -o {shlex.quote(executable)}")
+        if err_code != 0:
+            return output
+        err_code, output = exec_command(shlex.quote(executable))
+        return f"exit with {err_code}:\n{output}" if err_code != 0 else None
+
+    def exec_test(self, args: CliNameSpace):
+        snippets = []
+        for markdown in [x for x in args.files.split(",") if x]:
+            if os.path.isfile(markdown):
+                snippets.extend(extract_markdown_snippets(markdown))
+        if os.path.isdir(args.include_dir):
+            for header in find_headers(args.include_dir):
+                snippets.extend(extract_header_snippets(header))
+        snippets = [x for x in snippets if "ignore" not in x["flags"]]
+        failed = 0
+        with tempfile.TemporaryDirectory() as work_dir:
+            for snippet in snippets:
+                location = f"{snippet['file']}:{snippet['line']}"
+                error = self.test_snippet(args, snippet, work_dir)
+                if error is None:
+                    print(f"ok      {location}")
+                else:
+                    failed += 1
+                    print(f"FAILED  {location}\n{error}")
+        print("\n" + tr("{passed} passed, {failed} failed of {total} snippets",
+                        passed=len(snippets) - failed, failed=failed, total=len(snippets)))
+        if failed:
+            sys.exit(ExitCode.CHECK_FAILURE)
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        if args.target == "test":
+            self.exec_test(args)
+            return
+        if not os.path.isdir(args.include_dir):
+            logger.error(f"Include dir not found: {args.include_dir}")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        reports = compute_coverage(args.include_dir)
+        dirs = {}
+        for report in reports:
+            stat = dirs.setdefault(report["dir"], [0, 0])
+            stat[0] += sum([1 for x in report["symbols"] if x["documented"]])
+            stat[1] += len(report["symbols"])
+        documented = sum([x[0] for x in dirs.values()])
+        total = sum([x[1] for x in dirs.values()])
+        coverage = self.percent(documented, total)
+        if args.format == "json":
+            print(json.dumps({
+                "coverage": round(coverage, 2),
+                "documented": documented,
+                "total": total,
+                "dirs": {k: {"documented": v[0], "total": v[1]} for k, v in sorted(dirs.items())},
+                "missing": [
+                    {"file": r["file"], "line": x["line"], "name": x["name"], "kind": x["kind"]}
+                    for r in reports for x in r["symbols"] if not x["documented"]
+                ],
+            }, indent=2))
+        else:
+            print(f"{'dir':<40} {'documented':>10} {'total':>6} {'coverage':>9}")
+            for dir_name, (dir_documented, dir_total) in sorted(dirs.items()):
+                print(f"{dir_name:<40} {dir_documented:>10} {dir_total:>6} {self.percent(dir_documented, dir_total):>8.1f}%")
+            print(f"{'total':<40} {documented:>10} {total:>6} {coverage:>8.1f}%")
+            if args.list_missing:
+                print("\n" + tr("Missing doc comments:"))
+                for report in reports:
+                    for x in [x for x in report["symbols"] if not x["documented"]]:
+                        print(f"{os.path.join(args.include_dir, report['file'])}:{x['line']}: {x['kind']} {x['name']}")
+        if coverage < args.threshold:
+            logger.error(tr("Doc coverage {coverage:.1f}% is lower than threshold {threshold}%",
+                            coverage=coverage, threshold=args.threshold))
+            sys.exit(ExitCode.CHECK_FAILURE)
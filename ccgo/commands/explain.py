@@ -0,0 +1,83 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.error.error_code import ERROR_CODES
+from utils.error.error_code import format_error_code
+from utils.error.error_code import parse_error_code
+from utils.error.error_code import find_error
+from utils.error.error_code import SUBSYSTEM_ERROR_CODES
+from utils.error.exit_code import ExitCode
+from utils.i18n.i18n_util import tr
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+class Explain(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to explain the error codes of ccgo.
+        """
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'code',
+            nargs="?",
+            help="error code like E0203 or E0004, or the exit code 4, list all codes if not given",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        if not args.code:
+            for exit_code, error in sorted(ERROR_CODES.items()):
+                print(f"{format_error_code(exit_code)}  {tr(error['title'])}")
+                # the specific errors of the subsystems under their category
+                for code, subsystem_error in sorted(SUBSYSTEM_ERROR_CODES.items()):
+                    if subsystem_error["exit_code"] == exit_code:
+                        print(f"  {code}  {tr(subsystem_error['title'])}")
+            return
+        code, error = find_error(args.code)
+        if error is None:
+            logger.error(tr("Unknown error code {code}, see `ccgo explain` for all codes", code=args.code))
+            sys.exit(ExitCode.USAGE_ERROR)
+        exit_code = error.get("exit_code", parse_error_code(code))
+        print(tr("{code}: {title} (exit code {exit_code})",
+                 code=code, title=tr(error["title"]), exit_code=exit_code) + "\n")
+        print(f"{tr(error['description'])}\n")
+        print(tr("Common causes:"))
+        for cause in error["causes"]:
+            print(f"  - {tr(cause)}")
+        print("\n" + tr("Fixes:"))
+        for fix in error["fixes"]:
+            print(f"  - {tr(fix)}")
@@ -0,0 +1,158 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import zipfile
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.cmd.cmd_util import exec_command
+from utils.git.git_util import get_last_tag
+from utils.github.github_util import get_origin_repo
+from utils.provenance.provenance_util import get_file_sha256
+from utils.homebrew.formula_util import get_archive_layout
+from utils.homebrew.formula_util import render_formula
+from utils.error.exit_code import ExitCode
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+class Export(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to export the published library to package managers.
+        """
+
+    def get_target_list(self) -> list:
+        return ["homebrew"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            'archive',
+            help="macos sdk zip published to the github release, like bin/foo_MACOS_SDK-1.0.0.zip",
+        )
+        parser.add_argument(
+            "--name",
+            action="store",
+            help="formula name, default is the github repo name",
+        )
+        parser.add_argument(
+            "--tag",
+            action="store",
+            help="release tag of the archive, default is the last tag",
+        )
+        parser.add_argument(
+            "--repo",
+            action="store",
+            help="github repository like owner/name, default is parsed from git remote origin",
+        )
+        parser.add_argument(
+            "--url",
+            action="store",
+            help="download url of the archive, default is the asset of the github release",
+        )
+        parser.add_argument(
+            "--desc",
+            action="store",
+            help="description of the formula",
+        )
+        parser.add_argument(
+            "--tap-dir",
+            action="store",
+            help="local clone of the tap repository to write Formula/<name>.rb into",
+        )
+        parser.add_argument(
+            "--push",
+            action="store_true",
+            help="commit and push the formula of --tap-dir",
+        )
+        parser.add_argument(
+            "--output",
+            action="store",
+            help="formula file, default is <name>.rb, or Formula/<name>.rb of --tap-dir",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def push_tap(self, tap_dir, formula_path, name, version):
+        relative_path = os.path.relpath(formula_path, tap_dir)
+        cmd = (f"git -C \"{tap_dir}\" add \"{relative_path}\" && "
+               f"git -C \"{tap_dir}\" commit -m \"{name} {version}\" && "
+               f"git -C \"{tap_dir}\" push")
+        logger.info(f"Execute command: {cmd}")
+        err_code, output = exec_command(cmd)
+        if err_code != 0:
+            logger.error(f"Failed to push the tap:\n{output}")
+            sys.exit(ExitCode.PUBLISH_ERROR)
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        if not zipfile.is_zipfile(args.archive):
+            logger.error(f"{args.archive} is not a zip")
+            sys.exit(ExitCode.USAGE_ERROR)
+        if args.push and not args.tap_dir:
+            logger.error("--push needs --tap-dir")
+            sys.exit(ExitCode.USAGE_ERROR)
+        repo = args.repo or get_origin_repo()
+        tag = args.tag or get_last_tag()
+        if not tag or not (args.url or repo):
+            logger.error("Can not detect github repository or tag, use --repo and --tag, or --url")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        name = args.name or (repo.split("/")[1] if repo else None)
+        if not name:
+            logger.error("Can not detect the formula name, use --name")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        name = name.lower()
+        url = args.url or f"https://github.com/{repo}/releases/download/{tag}/{os.path.basename(args.archive)}"
+        homepage = f"https://github.com/{repo}" if repo else url
+        layout = get_archive_layout(args.archive)
+        if not layout["libs"] and not layout["frameworks"]:
+            logger.error(f"No .a, .dylib or .framework found in {args.archive}")
+            sys.exit(ExitCode.PACKAGING_ERROR)
+        version = tag.lstrip("v")
+        formula = render_formula(name, version, url, get_file_sha256(args.archive), layout,
+                                 args.desc or f"{name} library built by ccgo", homepage)
+        if args.output:
+            output = args.output
+        elif args.tap_dir:
+            output = os.path.join(args.tap_dir, "Formula", f"{name}.rb")
+        else:
+            output = f"{name}.rb"
+        os.makedirs(os.path.dirname(os.path.abspath(output)), exist_ok=True)
+        with open(output, "w") as f:
+            f.write(formula)
+        logger.info(f"Generated {output}")
+        if args.push:
+            self.push_tap(args.tap_dir, output, name, version)
@@ -0,0 +1,181 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import io
+import os
+import re
+import sys
+import shutil
+import zipfile
+import hashlib
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.github.github_util import GitHubClient
+from utils.github.github_util import get_github_token
+from utils.package.package_util import is_sdk_archive_of
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import exit_with_error
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+# names of the checksum files attached to a release
+CHECKSUM_ASSET_NAMES = ["SHA256SUMS", "sha256sums.txt", "checksums.txt"]
+
+
+class Fetch(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to download the sdk archives published to github releases.
+        """
+
+    def get_target_list(self) -> list:
+        return ["sdk"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            'package',
+            help="github repo and version of the sdk, like owner/foo@1.2.0",
+        )
+        parser.add_argument(
+            "--platform",
+            action="store",
+            required=True,
+            help="platform of the sdk archive, like ios, android",
+        )
+        parser.add_argument(
+            "--output",
+            action="store",
+            help="dir to unpack the sdk into, default is .ccgo/sdk/<name>/<version>/<platform>",
+        )
+        parser.add_argument(
+            "--sha256",
+            action="store",
+            help="expected sha256 of the archive, default is read from the checksum files of the release",
+        )
+        parser.add_argument(
+            "--force",
+            action="store_true",
+            help="download again even if the output dir exists",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def get_release(self, client: GitHubClient, version):
+        for tag in [f"v{version}", version]:
+            status, release = client.get_release_by_tag(tag)
+            if status == 200:
+                return release
+            if status != 404:
+                logger.error(f"Failed to get release {tag} of {client.repo}: {status} {release}")
+                exit_with_error("E0501")
+        logger.error(f"Release {version} of {client.repo} not found")
+        sys.exit(ExitCode.USAGE_ERROR)
+
+    def find_archive(self, release, platform):
+        archives = [x for x in release["assets"] if x["name"].endswith(".zip")]
+        matched = [x for x in archives if is_sdk_archive_of(x["name"], platform)]
+        if len(matched) != 1:
+            names = "\n".join([f"  {x['name']}" for x in archives]) or "  (none)"
+            reason = "not found" if not matched else "is ambiguous"
+            logger.error(f"Archive of {platform} {reason}, zips of the release:\n{names}")
+            sys.exit(ExitCode.USAGE_ERROR)
+        return matched[0]
+
+    def find_expected_sha256(self, client: GitHubClient, release, archive):
+        # <archive>.sha256 first, then the sha256sum style files
+        assets = {x["name"]: x for x in release["assets"]}
+        for name in [f"{archive['name']}.sha256"] + CHECKSUM_ASSET_NAMES:
+            if name not in assets:
+                continue
+            status, body = client.download_asset(assets[name])
+            if status != 200:
+                logger.error(f"Failed to download {name}: {status}")
+                exit_with_error("E0501")
+            for line in body.decode("utf-8", "replace").splitlines():
+                fields = line.split()
+                if not fields or not re.fullmatch(r"[0-9a-fA-F]{64}", fields[0]):
+                    continue
+                if len(fields) == 1 or fields[-1].lstrip("*") == archive["name"]:
+                    return fields[0].lower()
+        return None
+
+    def unpack(self, data, output):
+        output_root = os.path.realpath(output)
+        with zipfile.ZipFile(io.BytesIO(data)) as archive:
+            for name in archive.namelist():
+                target = os.path.realpath(os.path.join(output_root, name))
+                if target != output_root and not target.startswith(output_root + os.sep):
+                    logger.error(f"Refuse to unpack {name} outside of {output}")
+                    sys.exit(ExitCode.CHECK_FAILURE)
+            archive.extractall(output_root)
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        repo, _, version = args.package.partition("@")
+        if repo.count("/") != 1 or not version:
+            logger.error(f"Invalid package {args.package}, use owner/name@version")
+            sys.exit(ExitCode.USAGE_ERROR)
+        output = args.output or os.path.join(".ccgo", "sdk", repo.split("/")[1], version, args.platform)
+        if os.path.isdir(output) and not args.force:
+            logger.info(f"{args.package} of {args.platform} already exists in {output}, use --force to download again")
+            return
+        client = GitHubClient(repo, get_github_token())
+        release = self.get_release(client, version)
+        archive = self.find_archive(release, args.platform)
+        expected_sha256 = args.sha256 or self.find_expected_sha256(client, release, archive)
+        logger.info(f"Downloading {archive['name']} ({archive['size']} bytes)...")
+        status, data = client.download_asset(archive)
+        if status != 200:
+            logger.error(f"Failed to download {archive['name']}: {status}")
+            exit_with_error("E0501")
+        if len(data) != archive["size"]:
+            logger.error(f"Downloaded {len(data)} bytes of {archive['name']}, expected {archive['size']}")
+            exit_with_error("E0501")
+        sha256 = hashlib.sha256(data).hexdigest()
+        if expected_sha256 is None:
+            logger.warning(f"No checksum found for {archive['name']}, only its size is verified")
+        elif sha256 != expected_sha256.lower():
+            logger.error(f"Checksum of {archive['name']} mismatch: {sha256}, expected {expected_sha256}")
+            exit_with_error("E0704")
+        if os.path.isdir(output):
+            shutil.rmtree(output)
+        os.makedirs(output)
+        try:
+            self.unpack(data, output)
+        except zipfile.BadZipFile as e:
+            logger.error(f"Failed to unpack {archive['name']}: {e}")
+            exit_with_error("E0201")
+        logger.info(f"Unpacked {archive['name']} into {output}")
@@ -52,6 +52,8 @@ class Help(CliCommand):
         print("\n2. build a library")
         print("\nccgo build android --arch armeabi-v7a,arm64-v8a,x86_64")
         print("\nccgo build ios")
+        print("\n3. release a new version")
+        print("\nccgo tag minor --push")
         print("\n")
 
 
@@ -0,0 +1,178 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import json
+import time
+import shlex
+import argparse
+import subprocess
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.compile_db.compile_db_util import find_compile_db
+from utils.compile_db.compile_db_util import load_compile_db
+from utils.compile_db.compile_db_util import find_entry
+from utils.compile_db.compile_db_util import get_entry_arguments
+from utils.compile_db.compile_db_util import strip_output_arguments
+from utils.hardening.hardening_util import REQUIRED_MITIGATIONS
+from utils.hardening.hardening_util import check_binary
+from utils.trace.trace_util import record_command
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import exit_with_error
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+class Inspect(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to inspect sources with the exact flags of the build,
+        or check the security mitigations of the built binaries.
+        """
+
+    def get_target_list(self) -> list:
+        return ["source", "hardening"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument('path')
+        parser.add_argument(
+            "--preprocess",
+            action="store_true",
+            help="print the preprocessed source, like -E",
+        )
+        parser.add_argument(
+            "--ast",
+            action="store_true",
+            help="print the clang AST of the source",
+        )
+        parser.add_argument(
+            "--ast-filter",
+            action="store",
+            help="only dump the AST of declarations whose name contains it",
+        )
+        parser.add_argument(
+            "--compile-db",
+            action="store",
+            help="compile_commands.json or its dir, default is the newest one in cmake_build",
+        )
+        parser.add_argument(
+            "--readelf",
+            action="store",
+            default="readelf",
+            help="readelf used to check elf binaries, like llvm-readelf",
+        )
+        parser.add_argument(
+            "--objdump",
+            action="store",
+            default="objdump",
+            help="objdump used to check pe binaries and disassemble for stack buffers, like llvm-objdump",
+        )
+        parser.add_argument(
+            "--format",
+            action="store",
+            default="text",
+            choices=["text", "json"],
+            help="format of the hardening report",
+        )
+        parser.add_argument(
+            "--output",
+            action="store",
+            help="output file, default is stdout",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def inspect_source(self, args: CliNameSpace):
+        if args.preprocess == args.ast:
+            logger.error("Choose one of --preprocess and --ast")
+            sys.exit(ExitCode.USAGE_ERROR)
+        compile_db_path = find_compile_db(args.compile_db)
+        if not compile_db_path:
+            logger.error("No compile_commands.json found, build with CMAKE_EXPORT_COMPILE_COMMANDS=ON "
+                  "or use --compile-db")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        entry = find_entry(load_compile_db(compile_db_path), args.path)
+        if not entry:
+            logger.error(f"{args.path} is not compiled in {compile_db_path}")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        arguments = strip_output_arguments(get_entry_arguments(entry))
+        if args.preprocess:
+            arguments.append("-E")
+        else:
+            if "clang" not in os.path.basename(arguments[0]):
+                logger.error(f"--ast needs clang, but the source is compiled by {arguments[0]}")
+                sys.exit(ExitCode.TOOLCHAIN_MISSING)
+            arguments.extend(["-fsyntax-only", "-Xclang", "-ast-dump"])
+            if args.ast_filter:
+                arguments.extend(["-Xclang", "-ast-dump-filter", "-Xclang", args.ast_filter])
+        logger.info("Execute command: " + " ".join([shlex.quote(x) for x in arguments]))
+        output = open(args.output, "w") if args.output else None
+        start_time = time.time()
+        try:
+            err_code = subprocess.call(arguments, cwd=entry["directory"], stdout=output)
+        finally:
+            if output:
+                output.close()
+        record_command(arguments, entry["directory"], time.time() - start_time, err_code)
+        if err_code != 0:
+            sys.exit(ExitCode.COMPILE_ERROR)
+
+    def inspect_hardening(self, args: CliNameSpace):
+        if not os.path.isfile(args.path):
+            logger.error(f"{args.path} not found")
+            sys.exit(ExitCode.USAGE_ERROR)
+        try:
+            binary_format, result = check_binary(args.path, args.readelf, args.objdump)
+        except RuntimeError as e:
+            logger.error(f"{e}, pass the tools of the target toolchain with --readelf and --objdump")
+            exit_with_error("E0103")
+        if not binary_format:
+            logger.error(f"{args.path} is not an elf, mach-o, pe binary or static lib")
+            sys.exit(ExitCode.USAGE_ERROR)
+        if args.format == "json":
+            print(json.dumps({"path": args.path, "format": binary_format, "mitigations": result}, indent=2))
+        else:
+            print(f"{args.path} ({binary_format})")
+            for mitigation, status in result.items():
+                print(f"  {mitigation:<16} {status}")
+        if any([result.get(x) == "no" for x in REQUIRED_MITIGATIONS]):
+            exit_with_error("E0702")
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        if args.target == "hardening":
+            self.inspect_hardening(args)
+            return
+        self.inspect_source(args)
@@ -0,0 +1,106 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import time
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.journal.journal_util import get_journal_path
+from utils.journal.journal_util import load_journal
+
+class Journal(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to show the local journal of ccgo invocations.
+        """
+
+    def get_target_list(self) -> list:
+        return ["show", "stats"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            "--command",
+            action="store",
+            help="only show invocations of this command, like build",
+        )
+        parser.add_argument(
+            "--last",
+            action="store",
+            type=int,
+            default=20,
+            help="number of recent invocations to show, stats covers all of them",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def show(self, records, last):
+        print(f"{'time':<19} {'command':<12} {'target':<14} {'result':<8} {'duration(s)':>11}  error")
+        for record in records[-last:]:
+            record_time = time.strftime("%Y-%m-%d %H:%M:%S", time.localtime(record["time"]))
+            result = "ok" if record["success"] else f"exit {record['exit_code']}"
+            print(f"{record_time:<19} {record['command']:<12} {str(record['target'] or '-'):<14} "
+                  f"{result:<8} {record['duration_ms'] / 1000:>11.1f}  {record.get('error') or ''}")
+
+    def stats(self, records):
+        print(f"{'command':<12} {'target':<14} {'runs':>5} {'failed':>7} {'rate':>6} {'avg(s)':>8}")
+        keys = sorted(set([(x["command"], str(x["target"] or "-")) for x in records]))
+        for command, target in keys:
+            items = [x for x in records if x["command"] == command and str(x["target"] or "-") == target]
+            failed = len([x for x in items if not x["success"]])
+            avg_second = sum([x["duration_ms"] for x in items]) / len(items) / 1000
+            print(f"{command:<12} {target:<14} {len(items):>5} {failed:>7} "
+                  f"{failed * 100 / len(items):>5.0f}% {avg_second:>8.1f}")
+        errors = {}
+        for record in records:
+            if record.get("error"):
+                errors[record["error"]] = errors.get(record["error"], 0) + 1
+        if errors:
+            print("\nFailures by category:")
+            for error, count in sorted(errors.items(), key=lambda x: x[1], reverse=True):
+                print(f"  {count:>5}  {error}")
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        records = load_journal()
+        if args.command:
+            records = [x for x in records if x["command"] == args.command]
+        if not records:
+            print(f"No invocations found in {get_journal_path()}")
+            return
+        if args.target == "show":
+            self.show(records, args.last)
+        else:
+            self.stats(records)
@@ -26,6 +26,9 @@ PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
 from utils.context.namespace import CliNameSpace
 from utils.context.context import CliContext
 from utils.context.command import CliCommand
+from utils.log.log_util import get_logger
+
+logger = get_logger()
 
 class Lib(CliCommand):
     def description(self) -> str:
@@ -62,8 +65,8 @@ class Lib(CliCommand):
         return args
 
     def exec(self, context: CliContext, args: CliNameSpace):
-        print("Creating library project, with configuration...")
-        print(vars(args))
+        logger.info("Creating library project...")
+        logger.debug(f"configuration: {vars(args)}")
         if os.path.exists(args.dst_dir):
             # directory exists, recopy
             run_recopy(args.dst_dir, unsafe=True)
@@ -0,0 +1,154 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import zipfile
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.package.package_util import load_archive
+from utils.package.package_util import check_consistency
+from utils.package.package_util import find_conflicts
+from utils.package.package_util import merge_archives
+from utils.package.package_util import find_build_info_name
+from utils.package.package_util import add_file_to_archive
+from utils.license.license_util import DEFAULT_THIRD_PARTY_DIR
+from utils.license.license_util import NOTICES_NAME
+from utils.license.license_util import collect_licenses
+from utils.license.license_util import render_notices
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import exit_with_error
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+class Package(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to handle the sdk archives.
+        """
+
+    def get_target_list(self) -> list:
+        return ["merge", "notices"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            'archives',
+            nargs="*",
+            help="sdk zips to merge, like the ones built on macOS and linux, "
+                 "or to embed the notices into",
+        )
+        parser.add_argument(
+            "-o", "--output",
+            action="store",
+            help=f"merged sdk zip, or notices file of notices, default is {NOTICES_NAME}",
+        )
+        parser.add_argument(
+            "--third-party-dir",
+            action="store",
+            default=DEFAULT_THIRD_PARTY_DIR,
+            help="dir of the bundled dependencies, one sub dir for each",
+        )
+        parser.add_argument(
+            "--template",
+            action="store",
+            help="text file of the notices with {notices} replaced by the license texts",
+        )
+        parser.add_argument(
+            "--allow-inconsistent",
+            action="store_true",
+            help="merge even if name, version or revision of the build_info.json differ",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def notices(self, args: CliNameSpace):
+        deps = collect_licenses(args.third_party_dir)
+        missing = [x["name"] for x in deps if not x["files"]]
+        if missing:
+            logger.error(f"No license file found of {missing} in {args.third_party_dir}")
+            exit_with_error("E0703")
+        # a license that can not be identified has to be reviewed by hand
+        unknown = [x["name"] for x in deps if x["license"] == "unknown"]
+        if unknown:
+            logger.error(f"Unknown license of {unknown} in {args.third_party_dir}")
+            exit_with_error("E0703")
+        template = None
+        if args.template:
+            with open(args.template, "r") as f:
+                template = f.read()
+        content = render_notices(deps, template)
+        output = args.output or NOTICES_NAME
+        with open(output, "w") as f:
+            f.write(content)
+        for dep in deps:
+            logger.info(f"  {dep['name']:<24} {dep['license']}")
+        logger.info(f"Generated {output} of {len(deps)} dependencies")
+        for archive in args.archives:
+            # next to build_info.json, the root of the sdk
+            with zipfile.ZipFile(archive) as f:
+                build_info_name = find_build_info_name(f.namelist())
+            name = os.path.join(os.path.dirname(build_info_name), NOTICES_NAME) if build_info_name else NOTICES_NAME
+            add_file_to_archive(archive, name.replace(os.sep, "/"), content)
+            logger.info(f"Embedded {name} into {archive}")
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        if args.target == "notices":
+            self.notices(args)
+            return
+        if not args.archives or not args.output:
+            logger.error("Use like `ccgo package merge a.zip b.zip -o merged.zip`")
+            sys.exit(ExitCode.USAGE_ERROR)
+        archives = []
+        for path in args.archives:
+            try:
+                archives.append(load_archive(path))
+            except (OSError, zipfile.BadZipFile, ValueError) as e:
+                logger.error(f"Failed to read {path}: {e}")
+                exit_with_error("E0201")
+        errors = check_consistency(archives)
+        for error in errors:
+            logger.error(error)
+        if errors and not args.allow_inconsistent:
+            exit_with_error("E0203")
+        conflicts = find_conflicts(archives)
+        if conflicts:
+            for name, paths in sorted(conflicts.items()):
+                logger.error(f"{name} differs in {paths}")
+            logger.error(f"{len(conflicts)} files conflict, archives are not merged")
+            exit_with_error("E0202")
+        count = merge_archives(archives, args.output)
+        logger.info(f"Merged {len(archives)} archives with {count} files into {args.output}")
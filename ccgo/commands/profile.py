@@ -0,0 +1,174 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import time
+import shlex
+import shutil
+import argparse
+import subprocess
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.cmd.cmd_util import exec_command
+from utils.cmd.cmd_util import exec_command_system
+from utils.profile.profile_util import PROFILE_DIR
+from utils.profile.profile_util import FRAME_POINTER_FLAGS
+from utils.profile.profile_util import get_profiler_name
+from utils.profile.profile_util import collapse_perf_script
+from utils.profile.profile_util import write_folded
+from utils.profile.profile_util import find_flamegraph_renderer
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import exit_with_error
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+class Profile(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to profile a test or example under perf, xctrace or wpr.
+        Only the perf samples are folded and rendered as a flamegraph, the .trace of
+        xctrace is opened in Instruments and the .etl of wpr in Windows Performance Analyzer.
+        """
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            "--build",
+            action="store",
+            choices=["tests", "benches"],
+            help="build the tests or benches with frame pointers and debug info first",
+        )
+        parser.add_argument(
+            "--frequency",
+            action="store",
+            type=int,
+            default=999,
+            help="samples per second of perf",
+        )
+        parser.add_argument(
+            "--output-dir",
+            action="store",
+            default=PROFILE_DIR,
+            help="dir of the profile results",
+        )
+        parser.add_argument(
+            'program',
+            nargs=argparse.REMAINDER,
+            help="executable to profile and its arguments, like cmake_build/tests/foo_test --gtest_filter=Net*",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def build(self, target):
+        for name in ["CFLAGS", "CXXFLAGS"]:
+            os.environ[name] = " ".join([x for x in [os.environ.get(name), FRAME_POINTER_FLAGS] if x])
+        cmd = f"python3 build_{target}.py 1"
+        logger.info(f"Execute command: {cmd}")
+        err_code = exec_command_system(cmd)
+        if err_code != 0:
+            logger.error(f"Build {target} failed with exit code {err_code}")
+            sys.exit(ExitCode.COMPILE_ERROR)
+
+    def profile_perf(self, args: CliNameSpace, program, output_prefix):
+        perf_data = f"{output_prefix}.perf.data"
+        cmd = f"perf record -F {args.frequency} -g -o {shlex.quote(perf_data)} -- {program}"
+        logger.info(f"Execute command: {cmd}")
+        # the exit code of the program is reported, the samples are still useful
+        err_code = exec_command_system(cmd)
+        if not os.path.isfile(perf_data):
+            logger.error(f"perf record failed with exit code {err_code}, check kernel.perf_event_paranoid")
+            sys.exit(ExitCode.GENERAL_ERROR)
+        script_code, output = exec_command(f"perf script -i {shlex.quote(perf_data)}")
+        if script_code != 0:
+            logger.error(f"perf script failed:\n{output}")
+            sys.exit(ExitCode.GENERAL_ERROR)
+        folded_path = f"{output_prefix}.folded"
+        write_folded(folded_path, collapse_perf_script(output))
+        renderer = find_flamegraph_renderer()
+        if not renderer:
+            logger.warning(f"Install inferno or FlameGraph to render {folded_path} as svg")
+            return folded_path, err_code
+        svg_path = f"{output_prefix}.svg"
+        exec_command_system(f"{renderer} {shlex.quote(folded_path)} > {shlex.quote(svg_path)}")
+        return svg_path, err_code
+
+    def profile_xctrace(self, args: CliNameSpace, program, output_prefix):
+        trace_path = f"{output_prefix}.trace"
+        cmd = f"xcrun xctrace record --template 'Time Profiler' --output {shlex.quote(trace_path)} --launch -- {program}"
+        logger.info(f"Execute command: {cmd}")
+        err_code = exec_command_system(cmd)
+        if not os.path.exists(trace_path):
+            logger.error(f"xctrace record failed with exit code {err_code}")
+            sys.exit(ExitCode.GENERAL_ERROR)
+        logger.info(f"Open {trace_path} in Instruments, it is not folded into a flamegraph")
+        return trace_path, err_code
+
+    def profile_wpr(self, args: CliNameSpace, program, output_prefix):
+        etl_path = f"{output_prefix}.etl"
+        start_code = exec_command_system("wpr -start CPU")
+        if start_code != 0:
+            logger.error(f"wpr -start failed with exit code {start_code}, run ccgo from an administrator prompt")
+            sys.exit(ExitCode.GENERAL_ERROR)
+        logger.info(f"Execute command: {program}")
+        err_code = exec_command_system(program)
+        stop_code = exec_command_system(f"wpr -stop \"{etl_path}\"")
+        if stop_code != 0 or not os.path.isfile(etl_path):
+            logger.error(f"wpr -stop failed with exit code {stop_code}")
+            sys.exit(ExitCode.GENERAL_ERROR)
+        logger.info(f"Open {etl_path} in Windows Performance Analyzer, it is not folded into a flamegraph")
+        return etl_path, err_code
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        if not args.program:
+            logger.error("Give the executable to profile, like `ccgo profile cmake_build/tests/foo_test`")
+            sys.exit(ExitCode.USAGE_ERROR)
+        profiler = get_profiler_name()
+        if not shutil.which("xcrun" if profiler == "xctrace" else profiler):
+            logger.error(f"{profiler} not found in PATH")
+            exit_with_error("E0104")
+        if args.build:
+            self.build(args.build)
+        os.makedirs(args.output_dir, exist_ok=True)
+        name = os.path.splitext(os.path.basename(args.program[0]))[0]
+        output_prefix = os.path.join(args.output_dir, f"{name}-{time.strftime('%Y%m%d-%H%M%S')}")
+        if profiler == "wpr":
+            # cmd.exe does not understand the single quotes of shlex
+            program = subprocess.list2cmdline(args.program)
+        else:
+            program = " ".join([shlex.quote(x) for x in args.program])
+        if profiler == "perf":
+            result_path, err_code = self.profile_perf(args, program, output_prefix)
+        elif profiler == "xctrace":
+            result_path, err_code = self.profile_xctrace(args, program, output_prefix)
+        else:
+            result_path, err_code = self.profile_wpr(args, program, output_prefix)
+        if err_code != 0:
+            logger.warning(f"{args.program[0]} exited with {err_code}")
+        logger.info(f"Profile saved to {result_path}")
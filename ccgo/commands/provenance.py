@@ -0,0 +1,158 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import json
+import glob
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.provenance.provenance_util import PROVENANCE_SUFFIX
+from utils.provenance.provenance_util import SIGNATURE_SUFFIX
+from utils.provenance.provenance_util import make_statement
+from utils.provenance.provenance_util import sign_file
+from utils.provenance.provenance_util import verify_file_signature
+from utils.provenance.provenance_util import verify_statement
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import exit_with_error
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+class Provenance(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to generate and verify slsa provenance of the sdk archives.
+        """
+
+    def get_target_list(self) -> list:
+        return ["generate", "verify"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            'archives',
+            nargs="+",
+            help="sdk archives, glob patterns like bin/*.zip are supported",
+        )
+        parser.add_argument(
+            "--param",
+            action="append",
+            default=[],
+            help="build parameter KEY=VALUE recorded in the provenance, can be repeated",
+        )
+        parser.add_argument(
+            "--key",
+            action="store",
+            help="private key pem to sign the provenance with openssl",
+        )
+        parser.add_argument(
+            "--public-key",
+            action="store",
+            help="public key pem to verify the provenance signature with",
+        )
+        parser.add_argument(
+            "--revision",
+            action="store",
+            help="git revision the archives must be built from",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def find_archives(self, patterns) -> list:
+        archives = []
+        for pattern in patterns:
+            matched = sorted([x for x in glob.glob(pattern) if not x.endswith((PROVENANCE_SUFFIX, SIGNATURE_SUFFIX))])
+            if not matched:
+                logger.error(f"No archive matches {pattern}")
+                sys.exit(ExitCode.USAGE_ERROR)
+            archives.extend(matched)
+        return archives
+
+    def generate(self, args: CliNameSpace, archives):
+        parameters = {}
+        for param in args.param:
+            key, sep, value = param.partition("=")
+            if not sep:
+                logger.error(f"Invalid --param {param}, use KEY=VALUE")
+                sys.exit(ExitCode.USAGE_ERROR)
+            parameters[key] = value
+        for archive in archives:
+            path = f"{archive}{PROVENANCE_SUFFIX}"
+            with open(path, "w") as f:
+                json.dump(make_statement(archive, parameters), f, indent=2)
+                f.write("\n")
+            if args.key and sign_file(path, args.key) != 0:
+                logger.error(f"Failed to sign {path} with {args.key}")
+                sys.exit(ExitCode.CONFIG_ERROR)
+            logger.info(f"Generated {path}{' and its signature' if args.key else ''}")
+
+    def verify(self, args: CliNameSpace, archives):
+        failed = 0
+        for archive in archives:
+            path = f"{archive}{PROVENANCE_SUFFIX}"
+            if not os.path.isfile(path):
+                errors = [f"{path} not found"]
+            elif args.public_key and not os.path.isfile(f"{path}{SIGNATURE_SUFFIX}"):
+                errors = [f"{path}{SIGNATURE_SUFFIX} not found"]
+            elif args.public_key and not verify_file_signature(path, args.public_key):
+                errors = ["signature of the provenance is invalid"]
+            else:
+                with open(path, "r") as f:
+                    try:
+                        statement = json.load(f)
+                        errors = verify_statement(statement, archive, args.revision)
+                        source = statement["predicate"]["buildDefinition"]["resolvedDependencies"][0]
+                        built_from = f"{source['uri']}@{source['digest']['gitCommit']}"
+                        builder = statement["predicate"]["runDetails"]["builder"]["id"]
+                    except (ValueError, KeyError, IndexError, TypeError) as e:
+                        errors = [f"invalid provenance: {e}"]
+            if errors:
+                failed += 1
+                print(f"FAILED  {archive}")
+                for error in errors:
+                    print(f"  {error}")
+                continue
+            signed = "signed" if args.public_key else "unsigned"
+            print(f"ok      {archive} ({signed}, {built_from}, built by {builder})")
+        if failed:
+            exit_with_error("E0704")
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        archives = self.find_archives(args.archives)
+        if args.target == "generate":
+            self.generate(args, archives)
+        else:
+            self.verify(args, archives)
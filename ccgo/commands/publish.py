@@ -11,6 +11,7 @@
 
 import os
 import sys
+import glob
 import argparse
 # setup path
 # >>>>>>>>>>>>>>
@@ -25,18 +26,35 @@ from utils.context.namespace import CliNameSpace
 from utils.context.context import CliContext
 from utils.context.command import CliCommand
 from utils.cmd.cmd_util import exec_command
+from utils.env.env_util import load_env
+from utils.env.env_util import apply_env
+from utils.env.env_util import mask_env_args
+from utils.git.git_util import get_last_tag
+from utils.github.github_util import GitHubClient
+from utils.github.github_util import get_github_token
+from utils.github.github_util import get_origin_repo
+from utils.toolchain.toolchain_config_util import apply_toolchain_config
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import exit_with_error
+from utils.cache.cache_util import format_size
+from utils.log.log_util import get_logger
+from utils.i18n.i18n_util import tr
+
+logger = get_logger()
 
 class Publish(CliCommand):
     def description(self) -> str:
         return """
-        This is a subcommand to publish the library to maven repository.
+        This is a subcommand to publish the library to maven repository,
+        or upload the built archives to a GitHub release.
         """
     
     def get_target_list(self) -> list:
         return [
             "android", "ios", "windows",
             "linux", "macos",
-            "tests", "benches"
+            "tests", "benches",
+            "github-release"
         ]
 
     def cli(self) -> CliNameSpace:
@@ -52,21 +70,184 @@ class Publish(CliCommand):
             type=str,
             choices=self.get_target_list(),
         )
+        parser.add_argument(
+            "--tag",
+            action="store",
+            help="tag of the github release, default is the last tag",
+        )
+        parser.add_argument(
+            "--repo",
+            action="store",
+            help="github repository like owner/name, default is parsed from origin",
+        )
+        parser.add_argument(
+            "--assets",
+            action="store",
+            default="",
+            help="files to upload to the github release, if more than one, use ',' to split them, glob is supported",
+        )
+        parser.add_argument(
+            "--notes-file",
+            action="store",
+            help="markdown file used as the body of a new github release",
+        )
+        # None keeps the draft and prerelease marks of an existing release
+        parser.add_argument(
+            "--draft",
+            action="store_const",
+            const=True,
+            help="mark the github release as draft",
+        )
+        parser.add_argument(
+            "--no-draft",
+            action="store_const",
+            dest="draft",
+            const=False,
+            help="publish the draft github release",
+        )
+        parser.add_argument(
+            "--prerelease",
+            action="store_const",
+            const=True,
+            help="mark the github release as prerelease",
+        )
+        parser.add_argument(
+            "--no-prerelease",
+            action="store_const",
+            dest="prerelease",
+            const=False,
+            help="clear the prerelease mark of the github release",
+        )
+        parser.add_argument(
+            "--env",
+            action="append",
+            default=[],
+            help="environment variable KEY=VALUE passed to the build tools, can be repeated",
+        )
+        parser.add_argument(
+            "--env-file",
+            action="store",
+            help="dotenv file of environment variables passed to the build tools, like .env",
+        )
+        parser.add_argument(
+            "--dry-run",
+            action="store_true",
+            help="print the command, release and assets of the publishing without running it",
+        )
         module_name = os.path.splitext(os.path.basename(__file__))[0]
         input_argv = [x for x in sys.argv[1:] if x != module_name]
         args, unknown = parser.parse_known_args(input_argv)
         return args
 
-    def exec(self, context: CliContext, args: CliNameSpace):
-        print("Publishing library project, with configuration...")
-        print(vars(args))
-        if args.target != "android":
-            print("\nPublishing only support maven of android now")
-            sys.exit(1)
-        # do publish
+    def publish_maven(self, args: CliNameSpace):
         cmd = f"./gradlew --stop && ./gradlew publishMainPublicationToMavenRepository"
+        if args.dry_run:
+            print(tr("Publish plan:"))
+            print(tr("  command:     {cmd}", cmd=cmd))
+            print(tr("  destination: maven repository of the gradle project"))
+            print("\n" + tr("Nothing was published, run again without --dry-run to publish"))
+            return
         err_code, err_msg = exec_command(cmd)
         if err_code != 0:
-            print("\nEnd with error:")
-            print(err_msg)
+            logger.error(f"End with error:\n{err_msg}")
+            sys.exit(ExitCode.PUBLISH_ERROR)
+
+    def publish_github_release(self, args: CliNameSpace):
+        token = get_github_token()
+        if not token and not args.dry_run:
+            logger.error("GITHUB_TOKEN or GH_TOKEN must be set to publish a github release")
+            exit_with_error("E0601")
+        repo = args.repo or get_origin_repo()
+        tag = args.tag or get_last_tag()
+        if not repo or not tag:
+            logger.error("Can not detect github repository or tag, use --repo and --tag")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        assets = []
+        for pattern in [x for x in args.assets.split(",") if x]:
+            matched = sorted(glob.glob(pattern))
+            if not matched:
+                logger.error(f"Asset not found: {pattern}")
+                sys.exit(ExitCode.PACKAGING_ERROR)
+            assets.extend(matched)
+        if not assets:
+            logger.error("No assets to upload, use like --assets \"bin/*.zip\"")
+            sys.exit(ExitCode.USAGE_ERROR)
+        body = ""
+        if args.notes_file:
+            try:
+                with open(args.notes_file, "r") as f:
+                    body = f.read()
+            except OSError as e:
+                logger.error(f"Failed to read the notes file: {e}")
+                sys.exit(ExitCode.CONFIG_ERROR)
+        if args.dry_run:
+            self.print_github_release_plan(args, repo, tag, assets)
+            return
+        client = GitHubClient(repo, token)
+        status, release = client.get_release_by_tag(tag)
+        if status == 404:
+            logger.info(f"Creating github release {tag} of {repo}")
+            status, release = client.create_release(tag, tag, body, bool(args.draft), bool(args.prerelease))
+        elif status == 200 and (args.draft is not None or args.prerelease is not None):
+            logger.info(f"Updating github release {tag} of {repo}")
+            status, release = client.update_release(release["id"], args.draft, args.prerelease)
+        if status not in [200, 201]:
+            logger.error(f"End with error: github api returns {status}\n{release}")
+            sys.exit(ExitCode.NETWORK_ERROR)
+        uploaded = {x["name"]: x for x in release.get("assets", [])}
+        for file_path in assets:
+            name = os.path.basename(file_path)
+            asset = uploaded.get(name)
+            if asset and asset["state"] == "uploaded" and asset["size"] == os.path.getsize(file_path):
+                # uploaded by a previous run, skip it to resume
+                logger.info(f"Skip uploaded asset {name}")
+                continue
+            if asset:
+                # remove the broken asset left by an interrupted upload
+                client.delete_asset(asset["id"])
+            logger.info(f"Uploading asset {name}")
+            status, body = client.upload_asset(release, file_path)
+            if status != 201:
+                logger.error(f"End with error: upload {name} returns {status}\n{body.decode('utf-8', 'replace')}")
+                sys.exit(ExitCode.NETWORK_ERROR)
+        logger.info(f"Published {release.get('html_url')}")
+
+    def print_github_release_plan(self, args: CliNameSpace, repo, tag, assets):
+        print(tr("Publish plan:"))
+        print(tr("  destination: https://github.com/{repo}/releases/tag/{tag}", repo=repo, tag=tag))
+        flags = []
+        for name in ["draft", "prerelease"]:
+            if getattr(args, name) is not None:
+                flags.append(name if getattr(args, name) else f"not {name}")
+        if flags:
+            print(tr("  release:     {flags}", flags=", ".join(flags)))
+        if args.notes_file:
+            print(tr("  notes:       {path}", path=args.notes_file))
+        print(tr("  assets:"))
+        for file_path in assets:
+            print(f"    {file_path} ({format_size(os.path.getsize(file_path))})")
+        print("\n" + tr("Nothing was published, run again without --dry-run to publish"))
 
+    def setup_env(self, args: CliNameSpace):
+        try:
+            env = load_env(args.env_file, args.env)
+        except (OSError, ValueError) as e:
+            logger.error(f"Failed to load environment: {e}")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        if env:
+            logger.info("Environment:")
+            apply_env(env)
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        logger.info("Publishing library project...")
+        logger.debug(f"configuration: { {**vars(args), 'env': mask_env_args(args.env)} }")
+        apply_toolchain_config()
+        self.setup_env(args)
+        if args.target == "github-release":
+            self.publish_github_release(args)
+            return
+        if args.target != "android":
+            logger.error("Publishing only support maven of android and github release now")
+            sys.exit(ExitCode.USAGE_ERROR)
+        # do publish
+        self.publish_maven(args)
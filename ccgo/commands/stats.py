@@ -0,0 +1,82 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import time
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.metrics.metrics_util import load_build_metrics
+from utils.metrics.metrics_util import get_metrics_path
+
+class Stats(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to show the recorded build metrics,
+        enable recording with `ccgo build <platform> --metrics` or CCGO_METRICS=1.
+        """
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            "--platform",
+            action="store",
+            help="only show metrics of this platform",
+        )
+        parser.add_argument(
+            "--last",
+            action="store",
+            type=int,
+            default=10,
+            help="number of recent builds to show",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        records = load_build_metrics()
+        if args.platform:
+            records = [x for x in records if x["platform"] == args.platform]
+        if not records:
+            print(f"No build metrics found in {get_metrics_path()}")
+            return
+        print("\nSummary:")
+        print(f"{'platform':<10} {'builds':>6} {'success':>8} {'avg(s)':>8} {'last(s)':>8}")
+        platforms = sorted(set([x["platform"] for x in records]))
+        for platform in platforms:
+            items = [x for x in records if x["platform"] == platform]
+            success_rate = sum([1 for x in items if x["success"]]) * 100 / len(items)
+            avg_second = sum([x["duration_ms"] for x in items]) / len(items) / 1000
+            last_second = items[-1]["duration_ms"] / 1000
+            print(f"{platform:<10} {len(items):>6} {success_rate:>7.0f}% {avg_second:>8.1f} {last_second:>8.1f}")
+        print(f"\nLast {args.last} builds:")
+        for record in records[-args.last:]:
+            build_time = time.strftime("%Y-%m-%d %H:%M:%S", time.localtime(record["time"]))
+            status = "ok" if record["success"] else f"failed({record['exit_code']})"
+            print(f"{build_time}  {record['platform']:<10} {record['duration_ms'] / 1000:>8.1f}s  {status}")
@@ -0,0 +1,178 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import json
+import time
+import shutil
+import zipfile
+import subprocess
+import argparse
+import tempfile
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.crash.crash_util import index_symbol_files
+from utils.crash.crash_util import symbolicate
+from utils.crash.crash_util import make_breakpad_symbol_store
+from utils.crash.crash_util import is_dump_syms_input
+from utils.trace.trace_util import record_command
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import exit_with_error
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+class Symbolicate(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to symbolicate the stack traces of crash logs or minidumps
+        with the symbols archive.
+        """
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'crash',
+            nargs="?",
+            help="android tombstone, linux backtrace or apple crash log",
+        )
+        parser.add_argument(
+            "--minidump",
+            action="store",
+            help="minidump of crashpad or breakpad, processed by minidump-stackwalk",
+        )
+        parser.add_argument(
+            "--dump-syms",
+            action="store",
+            default="dump_syms",
+            help="dump_syms converting the libs to breakpad symbols",
+        )
+        parser.add_argument(
+            "--format",
+            action="store",
+            default="text",
+            choices=["text", "json"],
+            help="report format of minidump",
+        )
+        parser.add_argument(
+            "--symbols",
+            action="store",
+            required=True,
+            help="SYMBOLS.zip, or dir of the unstripped libs and dSYMs",
+        )
+        parser.add_argument(
+            "--readelf",
+            action="store",
+            default="readelf",
+            help="readelf used to match the build id of elf libs",
+        )
+        parser.add_argument(
+            "--output",
+            action="store",
+            help="output file, default is stdout",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def symbolicate_with(self, args: CliNameSpace, symbols_dir):
+        with open(args.crash, "r", errors="replace") as f:
+            text = f.read()
+        output, resolved, total = symbolicate(text, index_symbol_files(symbols_dir), args.readelf)
+        if args.output:
+            with open(args.output, "w") as f:
+                f.write(output)
+        else:
+            print(output, end="")
+        logger.info(f"Symbolicated {resolved} of {total} frames")
+
+    def process_minidump(self, args: CliNameSpace, symbols_dir):
+        if not shutil.which("minidump-stackwalk"):
+            logger.error("minidump-stackwalk not found, install it with `cargo install minidump-stackwalk`")
+            exit_with_error("E0104")
+        index = index_symbol_files(symbols_dir)
+        has_libs = any([is_dump_syms_input(x) for paths in index.values() for x in paths])
+        if has_libs and not shutil.which(args.dump_syms):
+            logger.error(f"{args.dump_syms} not found, install it with `cargo install dump_syms` or pass it with --dump-syms")
+            exit_with_error("E0104")
+        with tempfile.TemporaryDirectory() as store_dir:
+            count = make_breakpad_symbol_store(index, store_dir, args.dump_syms)
+            logger.info(f"Prepared breakpad symbols of {count} modules")
+            # the report is on stdout, the progress on stderr
+            arguments = ["minidump-stackwalk", "--json", "--symbols-path", store_dir, args.minidump]
+            start_time = time.time()
+            process = subprocess.run(arguments, stdout=subprocess.PIPE, stderr=subprocess.PIPE, universal_newlines=True)
+            record_command(arguments, None, time.time() - start_time, process.returncode)
+        try:
+            report = json.loads(process.stdout)
+        except ValueError:
+            logger.error(f"minidump-stackwalk failed with exit code {process.returncode}:\n{process.stderr}")
+            sys.exit(ExitCode.GENERAL_ERROR)
+        if args.format == "json":
+            output = json.dumps(report, indent=2) + "\n"
+        else:
+            output = self.format_minidump(report)
+        if args.output:
+            with open(args.output, "w") as f:
+                f.write(output)
+        else:
+            print(output, end="")
+        # the build id of a module without symbols does not match any lib
+        for module in report.get("modules", []):
+            if module.get("missing_symbols") and module.get("filename"):
+                logger.warning(f"No symbols of {module['filename']} ({module.get('debug_id')}) in {args.symbols}")
+
+    def format_minidump(self, report) -> str:
+        crash = report.get("crash_info") or {}
+        lines = [f"Crash: {crash.get('type')} at {crash.get('address')}"]
+        thread_index = crash.get("crashing_thread")
+        for index, thread in enumerate(report.get("threads", [])):
+            mark = " (crashed)" if index == thread_index else ""
+            lines.append(f"\nThread {index}{mark}")
+            for frame in thread.get("frames", []):
+                function = frame.get("function") or frame.get("offset")
+                location = f" at {frame['file']}:{frame.get('line')}" if frame.get("file") else ""
+                lines.append(f"  #{frame.get('frame'):02d} {frame.get('module') or '???'} {function}{location}")
+        return "\n".join(lines) + "\n"
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        crash = args.minidump or args.crash
+        if not crash or not os.path.isfile(crash):
+            logger.error(f"Crash log or --minidump {crash or ''} not found")
+            sys.exit(ExitCode.USAGE_ERROR)
+        handle = self.process_minidump if args.minidump else self.symbolicate_with
+        if os.path.isdir(args.symbols):
+            handle(args, args.symbols)
+            return
+        if not zipfile.is_zipfile(args.symbols):
+            logger.error(f"{args.symbols} is not a symbols zip or dir")
+            sys.exit(ExitCode.USAGE_ERROR)
+        with tempfile.TemporaryDirectory() as symbols_dir:
+            with zipfile.ZipFile(args.symbols) as archive:
+                archive.extractall(symbols_dir)
+            handle(args, symbols_dir)
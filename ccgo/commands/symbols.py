@@ -0,0 +1,113 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import json
+import shlex
+import shutil
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.symbols.symbols_util import find_static_libs
+from utils.symbols.symbols_util import find_duplicate_symbols
+from utils.symbols.symbols_util import demangle
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import exit_with_error
+from utils.log.log_util import get_logger
+from utils.i18n.i18n_util import tr
+
+logger = get_logger()
+
+class Symbols(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to inspect the symbols of the built libraries.
+        """
+
+    def get_target_list(self) -> list:
+        return ["duplicates"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            'paths',
+            nargs="+",
+            help="static libs, or dirs to search *.a and *.lib in",
+        )
+        parser.add_argument(
+            "--nm",
+            action="store",
+            default="nm",
+            help="nm of the target toolchain, like llvm-nm",
+        )
+        parser.add_argument(
+            "--format",
+            action="store",
+            default="text",
+            choices=["text", "json"],
+            help="report format",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        libs = find_static_libs(args.paths)
+        if not libs:
+            logger.error(f"No static libs found in {args.paths}")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        if not shutil.which(shlex.split(args.nm)[0]):
+            logger.error(f"{args.nm} not found, pass the nm of the toolchain with --nm")
+            exit_with_error("E0104")
+        duplicates, unreadable = find_duplicate_symbols(libs, args.nm)
+        if unreadable:
+            # never report the libs not scanned as free of duplicates
+            logger.error(f"Failed to read {len(unreadable)} of {len(libs)} static libs: {unreadable}")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        names = demangle(sorted(duplicates))
+        if args.format == "json":
+            print(json.dumps([{
+                "symbol": symbol,
+                "demangled": names[symbol],
+                "definitions": [{"lib": lib, "object": obj} for lib, obj in owners],
+            } for symbol, owners in sorted(duplicates.items())], indent=2))
+        else:
+            for symbol, owners in sorted(duplicates.items()):
+                print(f"\n{names[symbol]}")
+                for lib, obj in owners:
+                    print(f"  defined in {lib}({obj})")
+            print("\n" + tr("Found {duplicates} duplicate strong symbols in {libs} static libs",
+                           duplicates=len(duplicates), libs=len(libs)))
+        if duplicates:
+            exit_with_error("E0701")
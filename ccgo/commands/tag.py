@@ -0,0 +1,215 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import sys
+import argparse
+import datetime
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.cmd.cmd_util import exec_command
+from utils.git.git_util import get_last_tag
+from utils.git.git_util import get_commit_subjects
+from utils.git.git_util import get_toplevel_dir
+from utils.git.git_util import is_tag_exists
+from utils.git.git_util import is_work_tree_clean
+from utils.error.exit_code import ExitCode
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+CHANGELOG_FILE = "CHANGELOG.md"
+VERSION_PATTERN = re.compile(r"^v?(\d+)\.(\d+)\.(\d+)(?:-([0-9A-Za-z.-]+))?$")
+COMMIT_PATTERN = re.compile(r"^(\w+)(\([^)]*\))?(!)?: (.+)$")
+# changelog sections of conventional commits, in display order
+CHANGELOG_SECTIONS = [
+    ("breaking", "Breaking Changes"),
+    ("feat", "Features"),
+    ("fix", "Bug Fixes"),
+    ("perf", "Performance Improvements"),
+]
+
+class Tag(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to bump the version, update CHANGELOG.md
+        and create an annotated git tag.
+        """
+
+    def get_target_list(self) -> list:
+        return ["major", "minor", "patch", "prerelease"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            "--preid",
+            action="store",
+            default="beta",
+            help="identifier of the prerelease version, like alpha, beta, rc",
+        )
+        parser.add_argument(
+            "--no-changelog",
+            action="store_true",
+            help="do not update CHANGELOG.md",
+        )
+        parser.add_argument(
+            "--push",
+            action="store_true",
+            help="push the release commit and tag to origin",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def bump_version(self, version, target, preid) -> str:
+        match = VERSION_PATTERN.match(version)
+        if not match:
+            return None
+        major, minor, patch = [int(x) for x in match.groups()[:3]]
+        prerelease = match.group(4)
+        if target == "major":
+            # 2.0.0-beta.1 is released as 2.0.0
+            if not (prerelease and minor == 0 and patch == 0):
+                major, minor, patch = major + 1, 0, 0
+        elif target == "minor":
+            if not (prerelease and patch == 0):
+                minor, patch = minor + 1, 0
+        elif target == "patch":
+            if not prerelease:
+                patch += 1
+        else:
+            # 1.2.3 -> 1.2.4-beta.0 -> 1.2.4-beta.1
+            if prerelease and prerelease.startswith(preid + "."):
+                number = prerelease[len(preid) + 1:]
+                if number.isdigit():
+                    return f"{major}.{minor}.{patch}-{preid}.{int(number) + 1}"
+            if not prerelease:
+                patch += 1
+            return f"{major}.{minor}.{patch}-{preid}.0"
+        return f"{major}.{minor}.{patch}"
+
+    def render_changelog(self, version, subjects) -> str:
+        sections = {}
+        for subject in subjects:
+            match = COMMIT_PATTERN.match(subject)
+            if not match:
+                continue
+            commit_type, scope, breaking, summary = match.groups()
+            scope = scope[1:-1] if scope else None
+            entry = f"**{scope}:** {summary}" if scope else summary
+            key = "breaking" if breaking else commit_type
+            sections.setdefault(key, []).append(entry)
+        today = datetime.date.today().isoformat()
+        lines = [f"## v{version} ({today})", ""]
+        for key, title in CHANGELOG_SECTIONS:
+            if key not in sections:
+                continue
+            lines.append(f"### {title}")
+            lines.append("")
+            lines.extend([f"- {entry}" for entry in sections[key]])
+            lines.append("")
+        if len(lines) == 2:
+            lines.extend(["No notable changes.", ""])
+        return "\n".join(lines)
+
+    def read_changelog(self):
+        # None if there is no CHANGELOG.md yet
+        if not os.path.exists(CHANGELOG_FILE):
+            return None
+        with open(CHANGELOG_FILE, "r") as f:
+            return f.read()
+
+    def update_changelog(self, release_notes, content):
+        header = "# Changelog\n\n"
+        content = content or ""
+        if content.startswith(header):
+            content = content[len(header):]
+        with open(CHANGELOG_FILE, "w") as f:
+            f.write(header + release_notes + "\n" + content)
+
+    def restore_changelog(self, content):
+        if content is None:
+            if os.path.exists(CHANGELOG_FILE):
+                os.remove(CHANGELOG_FILE)
+            return
+        with open(CHANGELOG_FILE, "w") as f:
+            f.write(content)
+
+    def run_command(self, cmd) -> bool:
+        logger.info(f"Execute command: {cmd}")
+        err_code, err_msg = exec_command(cmd)
+        if err_code != 0:
+            logger.error(f"End with error:\n{err_msg}")
+            return False
+        return True
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        logger.info("Tagging library project...")
+        logger.debug(f"configuration: {vars(args)}")
+        if not get_toplevel_dir():
+            logger.error("Current dir is not in a git repository")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        if not is_work_tree_clean():
+            logger.error("Working tree is not clean, commit or stash the changes first")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        last_tag = get_last_tag()
+        version = self.bump_version(last_tag or "0.0.0", args.target, args.preid)
+        if not version:
+            logger.error(f"Last tag {last_tag} is not a semantic version")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        tag_name = f"v{version}"
+        if is_tag_exists(tag_name):
+            logger.error(f"Tag {tag_name} already exists")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        release_notes = self.render_changelog(version, get_commit_subjects(last_tag))
+        logger.info(f"Release {last_tag or '(none)'} -> {tag_name}")
+        print(release_notes)
+        committed = False
+        if not args.no_changelog:
+            # the preconditions are checked, only now touch the work tree
+            content = self.read_changelog()
+            self.update_changelog(release_notes, content)
+            if not (self.run_command(f"git add {CHANGELOG_FILE}")
+                    and self.run_command(f"git commit -m \"chore(release): {tag_name}\"")):
+                exec_command(f"git reset -q -- {CHANGELOG_FILE}")
+                self.restore_changelog(content)
+                sys.exit(ExitCode.GENERAL_ERROR)
+            committed = True
+        if not self.run_command(f"git tag -a {tag_name} -m \"Release {tag_name}\""):
+            if committed:
+                # drop the release commit, the work tree was clean before it
+                exec_command("git reset -q --hard HEAD~1")
+            sys.exit(ExitCode.GENERAL_ERROR)
+        if args.push and not self.run_command(f"git push origin HEAD {tag_name}"):
+            sys.exit(ExitCode.GENERAL_ERROR)
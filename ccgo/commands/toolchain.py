@@ -0,0 +1,200 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import json
+import argparse
+import subprocess
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.cmd.cmd_util import exec_command_system
+from utils.cmd.cmd_util import exec_command_with_input
+from utils.toolchain.ndk_util import find_android_home
+from utils.toolchain.ndk_util import find_sdkmanager
+from utils.toolchain.ndk_util import find_ndk_dir
+from utils.toolchain.ndk_util import get_ndk_version
+from utils.toolchain.xcode_util import find_xcode
+from utils.toolchain.toolchain_config_util import TOOLCHAINS_CONFIG_PATH
+from utils.toolchain.toolchain_config_util import load_toolchain_config
+from utils.toolchain.toolchain_config_util import set_toolchain
+from utils.toolchain.toolchain_config_util import get_custom_toolchains
+from utils.toolchain.toolchain_detect_util import detect_toolchains
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import exit_with_error
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+# one answer to each license prompt of sdkmanager
+LICENSE_ANSWERS = "y\n" * 100
+
+class Toolchain(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to list, install and pin the toolchains of the project.
+        """
+
+    def get_target_list(self) -> list:
+        return ["list", "install", "use"]
+
+    def get_toolchain_list(self) -> list:
+        return ["ndk", "xcode"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            'toolchain',
+            metavar=f"{self.get_toolchain_list()}",
+            type=str,
+            nargs="?",
+            choices=self.get_toolchain_list(),
+            help="kind of the toolchain, only ndk and xcode can be installed or pinned, "
+                 "the others are picked from PATH",
+        )
+        parser.add_argument(
+            'version',
+            nargs="?",
+            help="version of the toolchain, like 26.1.10909125 of ndk, an installed 26.1.x matches 26.1",
+        )
+        parser.add_argument(
+            "--version",
+            dest="version_option",
+            action="store",
+            help="same as the version argument",
+        )
+        parser.add_argument(
+            "--format",
+            action="store",
+            default="text",
+            choices=["text", "json"],
+            help="format of list",
+        )
+        parser.add_argument(
+            "--accept-licenses",
+            action="store_true",
+            help="accept the sdk licenses without prompting, for ci",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def list_toolchains(self, args: CliNameSpace):
+        toolchains = detect_toolchains()
+        if args.toolchain:
+            toolchains = [x for x in toolchains if x["kind"] == args.toolchain]
+        config = load_toolchain_config()
+        if not args.toolchain:
+            # registered for `ccgo build --toolchain <name>`
+            for name, toolchain in sorted(get_custom_toolchains(config).items()):
+                values = toolchain["values"]
+                path = values.get("c_compiler") or values.get("toolchain_file") or ""
+                toolchains.append({"kind": "custom", "name": name, "version": None, "path": path})
+        pinned_paths = [x.get("path") for x in config.values()]
+        for toolchain in toolchains:
+            toolchain["pinned"] = toolchain["path"] in pinned_paths
+        if args.format == "json":
+            print(json.dumps(toolchains, indent=2))
+            return
+        if not toolchains:
+            print("No toolchains found")
+            return
+        print(f"  {'kind':<9} {'name':<26} {'version':<40} path")
+        for toolchain in toolchains:
+            mark = "*" if toolchain["pinned"] else " "
+            version = (toolchain["version"] or "unknown")[:40]
+            print(f"{mark} {toolchain['kind']:<9} {toolchain['name']:<26} {version:<40} {toolchain['path']}")
+        if any([x["pinned"] for x in toolchains]):
+            print(f"\n* pinned in {TOOLCHAINS_CONFIG_PATH}")
+
+    def use_toolchain(self, args: CliNameSpace):
+        if args.toolchain == "ndk":
+            toolchain_dir = find_ndk_dir(find_android_home(), args.version)
+            version = get_ndk_version(toolchain_dir) if toolchain_dir else None
+        else:
+            # the version of the found xcode, not the "15" of the query
+            version, toolchain_dir = find_xcode(args.version) or (None, None)
+        if not toolchain_dir:
+            logger.error(f"{args.toolchain} {args.version} not found, see `ccgo toolchain list {args.toolchain}`")
+            exit_with_error("E0101" if args.toolchain == "ndk" else "E0102")
+        set_toolchain(args.toolchain, version, toolchain_dir)
+        logger.info(f"Pinned {args.toolchain} {version} at {toolchain_dir} in {TOOLCHAINS_CONFIG_PATH}")
+
+    def install_ndk(self, args: CliNameSpace):
+        android_home = find_android_home()
+        if not android_home:
+            logger.error("Android sdk not found, set ANDROID_HOME")
+            exit_with_error("E0101")
+        ndk_dir = find_ndk_dir(android_home, args.version)
+        if ndk_dir:
+            logger.info(f"Ndk {args.version} already installed at {ndk_dir}")
+        else:
+            sdkmanager = find_sdkmanager(android_home)
+            if not sdkmanager:
+                logger.error(f"sdkmanager not found in {android_home}/cmdline-tools or PATH, "
+                             "install the android command line tools first")
+                exit_with_error("E0101")
+            sdk_root = f"--sdk_root=\"{android_home}\""
+            cmd = f"\"{sdkmanager}\" {sdk_root} --install \"ndk;{args.version}\""
+            logger.info(f"Execute command: {cmd}")
+            # prompts of the licenses are answered by the user unless accepted
+            if args.accept_licenses:
+                exec_command_with_input(f"\"{sdkmanager}\" {sdk_root} --licenses", LICENSE_ANSWERS,
+                                        stdout=subprocess.DEVNULL)
+                err_code = exec_command_with_input(cmd, LICENSE_ANSWERS)
+            else:
+                err_code = exec_command_system(cmd)
+            ndk_dir = find_ndk_dir(android_home, args.version)
+            if err_code != 0 or not ndk_dir:
+                logger.error(f"Failed to install ndk {args.version} with sdkmanager, exit code {err_code}")
+                exit_with_error("E0101")
+        # builds of this project export it as ANDROID_NDK_HOME
+        version = get_ndk_version(ndk_dir)
+        set_toolchain("ndk", version, ndk_dir)
+        logger.info(f"Pinned ndk {version} in {TOOLCHAINS_CONFIG_PATH}")
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        args.version = args.version_option or args.version
+        if args.target == "list":
+            self.list_toolchains(args)
+            return
+        if not args.toolchain or not args.version:
+            logger.error(f"Use like `ccgo toolchain {args.target} ndk 26.1`")
+            sys.exit(ExitCode.USAGE_ERROR)
+        if args.target == "use":
+            self.use_toolchain(args)
+        elif args.toolchain == "ndk":
+            self.install_ndk(args)
+        else:
+            logger.error(f"Install {args.toolchain} is not supported, install it manually and `ccgo toolchain use` it")
+            sys.exit(ExitCode.USAGE_ERROR)
@@ -0,0 +1,126 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import subprocess
+import argparse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<<
+# import this project modules
+from utils.context.namespace import CliNameSpace
+from utils.context.context import CliContext
+from utils.context.command import CliCommand
+from utils.trace.trace_util import get_trace_path
+from utils.trace.trace_util import load_trace
+from utils.trace.trace_util import get_replay_env
+from utils.error.exit_code import ExitCode
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+class Trace(CliCommand):
+    def description(self) -> str:
+        return """
+        This is a subcommand to show the external commands recorded by `--trace`,
+        and replay one of them with the same cwd and environment.
+        """
+
+    def get_target_list(self) -> list:
+        return ["show", "replay"]
+
+    def cli(self) -> CliNameSpace:
+        parser = argparse.ArgumentParser(
+            # 获取文件名
+            prog=os.path.basename(__file__),
+            formatter_class = argparse.RawDescriptionHelpFormatter,
+            description=self.description(),
+        )
+        parser.add_argument(
+            'target',
+            metavar=f"{self.get_target_list()}",
+            type=str,
+            choices=self.get_target_list(),
+        )
+        parser.add_argument(
+            'step',
+            nargs="?",
+            type=int,
+            help="number of the step, show lists all steps if not given",
+        )
+        parser.add_argument(
+            "--file",
+            action="store",
+            default=get_trace_path(),
+            help="trace file written by `--trace`",
+        )
+        module_name = os.path.splitext(os.path.basename(__file__))[0]
+        input_argv = [x for x in sys.argv[1:] if x != module_name]
+        args, unknown = parser.parse_known_args(input_argv)
+        return args
+
+    def show_steps(self, steps):
+        print(f"{'step':>4} {'result':<8} {'duration(s)':>11}  command")
+        for step in steps:
+            result = "ok" if step["exit_code"] == 0 else f"exit {step['exit_code']}"
+            print(f"{step['step']:>4} {result:<8} {step['duration_ms'] / 1000:>11.1f}  {step['command']}")
+
+    def show_step(self, step):
+        print(f"step:     {step['step']}")
+        print(f"program:  {step['program']}")
+        print(f"args:     {step['args']}")
+        print(f"cwd:      {step['cwd']}")
+        print(f"duration: {step['duration_ms'] / 1000:.1f}s")
+        print(f"exit:     {step['exit_code']}")
+        print("env:")
+        for name in sorted(step["env"]):
+            print(f"  {name}={step['env'][name]}")
+
+    def replay(self, step):
+        if not os.path.isdir(step["cwd"]):
+            logger.error(f"Working dir of step {step['step']} not found: {step['cwd']}")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        env, missing = get_replay_env(step)
+        if missing:
+            logger.error(f"Secrets are not kept in the trace, set {missing} in the environment to replay it")
+            sys.exit(ExitCode.CONFIG_ERROR)
+        logger.info(f"Replaying step {step['step']} in {step['cwd']}: {step['command']}")
+        err_code = subprocess.call(step["command"], shell=True, cwd=step["cwd"], env=env)
+        if err_code != 0:
+            logger.error(f"Step {step['step']} failed with exit code {err_code}, "
+                         f"it was {step['exit_code']} when traced")
+            sys.exit(ExitCode.GENERAL_ERROR)
+
+    def exec(self, context: CliContext, args: CliNameSpace):
+        steps = load_trace(args.file)
+        if not steps:
+            print(f"No steps found in {args.file}, run a command with `--trace` first")
+            return
+        if args.step is None:
+            if args.target == "replay":
+                logger.error("Use like `ccgo trace replay 3`, see `ccgo trace show` for the steps")
+                sys.exit(ExitCode.USAGE_ERROR)
+            self.show_steps(steps)
+            return
+        if not 1 <= args.step <= len(steps):
+            logger.error(f"Step {args.step} not found, the trace has {len(steps)} steps")
+            sys.exit(ExitCode.USAGE_ERROR)
+        step = steps[args.step - 1]
+        if args.target == "show":
+            self.show_step(step)
+        else:
+            self.replay(step)
@@ -0,0 +1,106 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import sys
+import json
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.git.git_util import get_toplevel_dir
+
+# file:line:col: severity: message [check], file may start with a drive
+DIAGNOSTIC_PATTERN = re.compile(
+    r"^(?P<file>(?:[A-Za-z]:)?[^:\s][^:]*):(?P<line>\d+):(?P<column>\d+): "
+    r"(?P<severity>warning|error|style|performance|portability|information): "
+    r"(?P<message>.+?)(?: \[(?P<rule>[^\]]+)\])?$"
+)
+SARIF_SCHEMA = "https://json.schemastore.org/sarif-2.1.0.json"
+SARIF_LEVELS = {"error": "error", "warning": "warning"}
+
+
+def parse_diagnostics(tool, output) -> list:
+    diagnostics = []
+    seen = set()
+    for line in output.splitlines():
+        match = DIAGNOSTIC_PATTERN.match(line.strip())
+        if not match:
+            continue
+        diagnostic = match.groupdict()
+        diagnostic["tool"] = tool
+        diagnostic["line"] = int(diagnostic["line"])
+        diagnostic["column"] = int(diagnostic["column"])
+        diagnostic["rule"] = diagnostic["rule"] or f"{tool}-{diagnostic['severity']}"
+        # headers are reported once per translation unit including them
+        key = (diagnostic["file"], diagnostic["line"], diagnostic["column"], diagnostic["rule"])
+        if key in seen:
+            continue
+        seen.add(key)
+        diagnostics.append(diagnostic)
+    return diagnostics
+
+
+def to_sarif(diagnostics, tools, root_dir=None) -> dict:
+    runs = []
+    for tool in tools:
+        results = []
+        for diagnostic in [x for x in diagnostics if x["tool"] == tool]:
+            results.append({
+                "ruleId": diagnostic["rule"],
+                "level": SARIF_LEVELS.get(diagnostic["severity"], "note"),
+                "message": {"text": diagnostic["message"]},
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": {"uri": to_uri(diagnostic["file"], root_dir)},
+                        "region": {
+                            "startLine": diagnostic["line"],
+                            "startColumn": diagnostic["column"],
+                        },
+                    },
+                }],
+            })
+        runs.append({"tool": {"driver": {"name": tool}}, "results": results})
+    return {"$schema": SARIF_SCHEMA, "version": "2.1.0", "runs": runs}
+
+
+def to_uri(file_path, root_dir=None) -> str:
+    # code scanning expects paths relative to the repository root, not to
+    # the dir ccgo runs in
+    root_dir = root_dir or os.getcwd()
+    try:
+        relative_path = os.path.relpath(os.path.abspath(file_path), root_dir)
+    except ValueError:
+        # on another drive of windows
+        relative_path = None
+    if relative_path and not relative_path.startswith(".."):
+        file_path = relative_path
+    return file_path.replace(os.sep, "/")
+
+
+def to_text(diagnostics) -> str:
+    lines = []
+    for x in diagnostics:
+        lines.append(f"{x['file']}:{x['line']}:{x['column']}: {x['severity']}: {x['message']} [{x['rule']}] ({x['tool']})")
+    return "\n".join(lines)
+
+
+def format_report(diagnostics, tools, report_format) -> str:
+    if report_format == "sarif":
+        return json.dumps(to_sarif(diagnostics, tools, get_toplevel_dir()), indent=2)
+    if report_format == "json":
+        return json.dumps(diagnostics, indent=2)
+    return to_text(diagnostics)
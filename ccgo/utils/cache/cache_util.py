@@ -0,0 +1,109 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import glob
+import time
+import shutil
+
+DEFAULT_BUILD_DIR = "cmake_build"
+# sdks downloaded by `ccgo fetch sdk`, .ccgo/sdk/<name>/<version>/<platform>
+SDK_CACHE_PATTERN = os.path.join(".ccgo", "sdk", "*", "*", "*")
+SIZE_UNITS = {"": 1, "K": 1024, "M": 1024 ** 2, "G": 1024 ** 3, "T": 1024 ** 4}
+AGE_UNITS = {"s": 1, "m": 60, "h": 3600, "d": 86400, "w": 7 * 86400}
+
+
+def parse_size(text) -> int:
+    # "50GB", "512M", "1024" -> bytes
+    match = re.fullmatch(r"\s*(\d+(?:\.\d+)?)\s*([KMGT]?)i?B?\s*", text, re.IGNORECASE)
+    if not match:
+        raise ValueError(f"invalid size {text}, use like 50GB or 512M")
+    return int(float(match.group(1)) * SIZE_UNITS[match.group(2).upper()])
+
+
+def parse_age(text) -> int:
+    # "30d", "12h" -> seconds
+    match = re.fullmatch(r"\s*(\d+)\s*([smhdw])\s*", text)
+    if not match:
+        raise ValueError(f"invalid age {text}, use like 30d or 12h")
+    return int(match.group(1)) * AGE_UNITS[match.group(2)]
+
+
+def get_dir_usage(path):
+    # return (size, last used time) of all files in the dir
+    size = 0
+    last_used = os.path.getmtime(path)
+    for root, dirs, files in os.walk(path):
+        for name in files:
+            try:
+                stat = os.lstat(os.path.join(root, name))
+            except OSError:
+                continue
+            size += stat.st_size
+            last_used = max(last_used, stat.st_mtime)
+    return size, last_used
+
+
+def get_cache_entries(build_dir=DEFAULT_BUILD_DIR) -> list:
+    # return [{"path", "kind", "size", "last_used"}], each platform of the
+    # build dir and each fetched sdk is an entry
+    paths = []
+    if os.path.isdir(build_dir):
+        paths += [("build", os.path.join(build_dir, x)) for x in sorted(os.listdir(build_dir))]
+    paths += [("sdk", x) for x in sorted(glob.glob(SDK_CACHE_PATTERN))]
+    entries = []
+    for kind, path in paths:
+        if not os.path.isdir(path) or os.path.islink(path):
+            continue
+        size, last_used = get_dir_usage(path)
+        entries.append({"path": path, "kind": kind, "size": size, "last_used": last_used})
+    return entries
+
+
+def select_prune_entries(entries, older_than=None, max_size=None, keep=None) -> list:
+    # entries unused for older_than seconds, then the least recently used
+    # ones until the total size is under max_size
+    # the build scripts may name the dir of a platform like cmake_build/Android,
+    # never prune the kept ones for a different case
+    keep = [os.path.normpath(x).lower() for x in keep or []]
+    candidates = sorted([x for x in entries if os.path.normpath(x["path"]).lower() not in keep],
+                        key=lambda x: x["last_used"])
+    selected = []
+    if older_than is not None:
+        deadline = time.time() - older_than
+        selected = [x for x in candidates if x["last_used"] < deadline]
+    if max_size is not None:
+        total = sum([x["size"] for x in entries]) - sum([x["size"] for x in selected])
+        for entry in candidates:
+            if total <= max_size:
+                break
+            if entry not in selected:
+                selected.append(entry)
+                total -= entry["size"]
+    return selected
+
+
+def remove_entries(entries) -> int:
+    # return the bytes freed
+    freed = 0
+    for entry in entries:
+        shutil.rmtree(entry["path"], ignore_errors=True)
+        freed += entry["size"]
+    return freed
+
+
+def format_size(size) -> str:
+    for unit in ["B", "KB", "MB", "GB"]:
+        if size < 1024:
+            return f"{size:.0f}{unit}" if unit == "B" else f"{size:.1f}{unit}"
+        size /= 1024
+    return f"{size:.1f}TB"
@@ -9,13 +9,65 @@
 # notice shall be included in all copies or
 # substantial portions of the Software.
 
+import os
+import sys
 import subprocess
 import time
+import logging
 from threading import Timer
 
+from utils.trace.trace_util import record_command
+
 DEFAULT_TIMEOUT_SECOND = 10
 
 
+def get_exit_code(status) -> int:
+    # os.system returns the wait status on posix, the exit code on windows
+    if os.name != "posix":
+        return status
+    if os.WIFSIGNALED(status):
+        return 128 + os.WTERMSIG(status)
+    return os.WEXITSTATUS(status)
+
+
+def exec_command_system(command) -> int:
+    # like os.system, the output goes to the terminal
+    start_time = time.time()
+    err_code = get_exit_code(os.system(command))
+    record_command(command, None, time.time() - start_time, err_code)
+    return err_code
+
+
+def exec_command_with_input(command, input_text, stdout=None) -> int:
+    # answer the prompts of the command through stdin, without a shell
+    # pipe like `yes |` that windows does not have
+    start_time = time.time()
+    process = subprocess.run(command, shell=True, input=input_text.encode("utf-8"), stdout=stdout)
+    err_code = process.returncode if process.returncode >= 0 else 128 - process.returncode
+    record_command(command, None, time.time() - start_time, err_code)
+    return err_code
+
+
+def exec_command_streaming(command) -> int:
+    # print the output as it comes and also write it to the log file
+    output_logger = logging.getLogger("ccgo.output")
+    start_time = time.time()
+    process = subprocess.Popen(
+        command, shell=True, stdout=subprocess.PIPE, stderr=subprocess.STDOUT,
+    )
+    for line in iter(process.stdout.readline, b""):
+        text = line.decode("utf-8", "replace")
+        sys.stdout.write(text)
+        sys.stdout.flush()
+        output_logger.info(text.rstrip("\n"))
+    process.stdout.close()
+    err_code = process.wait()
+    # a negative return code is the signal killing it, same as get_exit_code
+    err_code = err_code if err_code >= 0 else 128 - err_code
+    record_command(command, None, time.time() - start_time, err_code)
+    return err_code
+
+
 def exec_command(command, stdout=subprocess.PIPE, stderr=subprocess.STDOUT):
     # timeout is 3 hours
     return exec_command_with_timeout_second(command, 3 * 3600)
@@ -37,6 +89,7 @@ def exec_command_with_timeout_second(command,
     finally:
         timer.cancel()
     err_code = compile_popen.returncode
+    record_command(command, None, time.time() - start_mills / 1000, err_code)
     err_msg = bytes.decode(stdout, "UTF-8")
     if err_code == -9:
         if not err_msg:
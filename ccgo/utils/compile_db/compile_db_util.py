@@ -0,0 +1,100 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import glob
+import json
+import shlex
+
+COMPILE_DB_FILE = "compile_commands.json"
+DEFAULT_BUILD_DIR = "cmake_build"
+# flags of the original compile which take the next argument as value
+OUTPUT_FLAGS_WITH_VALUE = ["-o", "-MF", "-MT", "-MQ"]
+OUTPUT_FLAGS = ["-c", "-MD", "-MMD", "-M", "-MM", "-MP"]
+# options which are not -o<file>
+OPTIONS_STARTING_WITH_O = ["-objcmt-", "-object", "-openmp"]
+# -MT and -MD of cl and clang-cl select the runtime library, only the
+# object file of /Fo is the output
+MSVC_DRIVERS = ["cl", "clang-cl"]
+MSVC_OUTPUT_FLAGS = ["/c", "-c"]
+MSVC_OUTPUT_PREFIXES = ["/Fo", "-Fo"]
+
+
+def find_compile_db(compile_db=None, build_dir=DEFAULT_BUILD_DIR):
+    # use the given file or dir, otherwise the newest one in the build dir
+    if compile_db:
+        if os.path.isdir(compile_db):
+            compile_db = os.path.join(compile_db, COMPILE_DB_FILE)
+        return compile_db if os.path.isfile(compile_db) else None
+    candidates = glob.glob(os.path.join(build_dir, "**", COMPILE_DB_FILE), recursive=True)
+    if not candidates:
+        return None
+    return max(candidates, key=os.path.getmtime)
+
+
+def load_compile_db(compile_db_path) -> list:
+    with open(compile_db_path, "r") as f:
+        entries = json.load(f)
+    for entry in entries:
+        entry["file"] = os.path.normpath(os.path.join(entry["directory"], entry["file"]))
+    return entries
+
+
+def get_entry_arguments(entry) -> list:
+    # entries have either "arguments" or a shell quoted "command"
+    if "arguments" in entry:
+        return list(entry["arguments"])
+    return shlex.split(entry["command"])
+
+
+def get_entry_output(entry):
+    arguments = get_entry_arguments(entry)
+    for i, argument in enumerate(arguments[:-1]):
+        if argument == "-o":
+            return os.path.join(entry["directory"], arguments[i + 1])
+    return entry.get("output")
+
+
+def is_msvc_driver(compiler) -> bool:
+    name = os.path.basename(compiler.replace("\\", "/")).lower()
+    return (name[:-len(".exe")] if name.endswith(".exe") else name) in MSVC_DRIVERS
+
+
+def strip_output_arguments(arguments) -> list:
+    # drop -c, -o and dependency file flags to rerun the compile in another mode
+    if arguments and is_msvc_driver(arguments[0]):
+        return [x for x in arguments if x not in MSVC_OUTPUT_FLAGS
+                and not any([x.startswith(prefix) for prefix in MSVC_OUTPUT_PREFIXES])]
+    result = []
+    skip_next = False
+    for argument in arguments:
+        if skip_next:
+            skip_next = False
+            continue
+        if argument in OUTPUT_FLAGS_WITH_VALUE:
+            skip_next = True
+            continue
+        if argument in OUTPUT_FLAGS:
+            continue
+        # -ofoo.o, but none of the options starting with -o
+        if argument.startswith("-o") and len(argument) > 2 and \
+                not any([argument.startswith(x) for x in OPTIONS_STARTING_WITH_O]):
+            continue
+        result.append(argument)
+    return result
+
+
+def find_entry(entries, source_file):
+    source_file = os.path.normpath(os.path.abspath(source_file))
+    for entry in entries:
+        if entry["file"] == source_file:
+            return entry
+    return None
@@ -0,0 +1,102 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import sys
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.git.git_util import get_last_tag
+from utils.git.git_util import get_head_revision
+
+CONFIG_HEADER_NAME = "ccgo_config.h"
+GENERATED_DIR = os.path.join(".ccgo", "generated")
+VERSION_PATTERN = re.compile(r"^v?(\d+)\.(\d+)\.(\d+)")
+
+
+def get_macro_name(name) -> str:
+    # "net-v2" -> "NET_V2"
+    return re.sub(r"\W", "_", name).upper()
+
+
+def render_config_header(platform, features) -> str:
+    tag = get_last_tag() or "0.0.0"
+    match = VERSION_PATTERN.match(tag)
+    major, minor, patch = match.groups() if match else ("0", "0", "0")
+    revision = get_head_revision() or ""
+    lines = [
+        "// generated by ccgo build --config-header, do not edit",
+        "#ifndef CCGO_CONFIG_H_",
+        "#define CCGO_CONFIG_H_",
+        "",
+        f"#define CCGO_VERSION \"{tag.lstrip('v')}\"",
+        f"#define CCGO_VERSION_MAJOR {major}",
+        f"#define CCGO_VERSION_MINOR {minor}",
+        f"#define CCGO_VERSION_PATCH {patch}",
+        f"#define CCGO_GIT_REVISION \"{revision}\"",
+        f"#define CCGO_GIT_REVISION_SHORT \"{revision[:8]}\"",
+        "",
+        f"#define CCGO_PLATFORM \"{platform}\"",
+        f"#define CCGO_PLATFORM_{get_macro_name(platform)} 1",
+    ]
+    if features:
+        lines.append("")
+    lines += [f"#define CCGO_FEATURE_{get_macro_name(x)} 1" for x in sorted(set(features))]
+    lines += ["", "#endif  // CCGO_CONFIG_H_", ""]
+    return "\n".join(lines)
+
+
+def get_config_header_dir(platform) -> str:
+    return os.path.abspath(os.path.join(GENERATED_DIR, platform, "include"))
+
+
+def write_config_header(platform, features) -> str:
+    # return the include dir of the header, only rewritten when changed to
+    # not rebuild everything including it
+    include_dir = get_config_header_dir(platform)
+    header_path = os.path.join(include_dir, CONFIG_HEADER_NAME)
+    content = render_config_header(platform, features)
+    if os.path.isfile(header_path):
+        with open(header_path, "r") as f:
+            if f.read() == content:
+                return include_dir
+    os.makedirs(include_dir, exist_ok=True)
+    with open(header_path, "w") as f:
+        f.write(content)
+    return include_dir
+
+
+def remove_stale_cmake_caches(build_dir, include_dir) -> list:
+    # CFLAGS only seeds CMAKE_C_FLAGS of a fresh configure, the caches
+    # configured without the include dir are removed to configure again
+    removed = []
+    for root, _, files in os.walk(build_dir):
+        if "CMakeCache.txt" not in files:
+            continue
+        path = os.path.join(root, "CMakeCache.txt")
+        with open(path, "r", errors="replace") as f:
+            if include_dir in f.read():
+                continue
+        os.remove(path)
+        removed.append(path)
+    return removed
+
+
+def get_archive_header_name(build_info_name) -> str:
+    # into the include dir next to build_info.json, the root of the sdk
+    root = os.path.dirname(build_info_name) if build_info_name else ""
+    return "/".join([x for x in [root, "include", CONFIG_HEADER_NAME] if x])
@@ -0,0 +1,177 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import sys
+import shlex
+import shutil
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.cmd.cmd_util import exec_command
+
+# "    #00 pc 000000000004f9ac  /data/app/.../lib/arm64/libfoo.so (foo+12) (BuildId: 1a2b)"
+TOMBSTONE_FRAME_PATTERN = re.compile(
+    r"#(?P<frame>\d+)\s+pc\s+(?P<address>[0-9a-fA-F]+)\s+(?P<module>\S+)(?:.*BuildId:\s*(?P<build_id>[0-9a-fA-F]+))?")
+# "/usr/lib/libfoo.so(+0x1234) [0x7f...]" of glibc backtrace_symbols, the
+# offset of "libfoo.so(foo+0x10)" is from the symbol, not the module, and is
+# not matched
+BACKTRACE_FRAME_PATTERN = re.compile(r"(?P<module>[^\s(]+)\(\+0x(?P<address>[0-9a-fA-F]+)\)")
+# "3   libfoo.dylib   0x000000010234abcd 0x102300000 + 305101" of apple crash logs
+APPLE_FRAME_PATTERN = re.compile(
+    r"^(?P<frame>\d+)\s+(?P<module>\S+)\s+0x(?P<address>[0-9a-fA-F]+)\s+0x(?P<load_address>[0-9a-fA-F]+)\s+\+\s+\d+")
+APPLE_CODE_TYPE_PATTERN = re.compile(r"^Code Type:\s*(?P<code_type>\S+)", re.MULTILINE)
+APPLE_ARCHS = {"ARM-64": "arm64", "X86-64": "x86_64", "ARM": "armv7"}
+# abi dir of the device path -> abi dir of the symbols
+ANDROID_ABIS = {"arm64": "arm64-v8a", "arm": "armeabi-v7a", "x86_64": "x86_64", "x86": "x86"}
+# converted to breakpad symbols by dump_syms, besides the dwarf of dSYM
+DUMP_SYMS_EXTENSIONS = [".so", ".dylib", ".dll", ".pdb"]
+
+
+def index_symbol_files(symbols_dir) -> dict:
+    # return {basename: [path]}, including the binaries inside dSYM bundles
+    index = {}
+    for root, dirs, files in os.walk(symbols_dir):
+        for name in files:
+            path = os.path.join(root, name)
+            index.setdefault(name, []).append(path)
+    return index
+
+
+def get_build_id(path, readelf="readelf"):
+    err_code, output = exec_command(f"{readelf} -n {shlex.quote(path)}")
+    match = re.search(r"Build ID:\s*([0-9a-fA-F]+)", output) if err_code == 0 else None
+    return match.group(1).lower() if match else None
+
+
+def find_symbol_file(index, module, build_id=None, readelf="readelf"):
+    # the module of the same build id, otherwise of the same abi dir
+    candidates = index.get(os.path.basename(module), [])
+    if not candidates:
+        return None
+    if build_id:
+        for path in candidates:
+            if get_build_id(path, readelf) == build_id.lower():
+                return path
+    abi = next((v for k, v in ANDROID_ABIS.items() if f"/{k}/" in module), None)
+    if abi:
+        for path in candidates:
+            if f"{os.sep}{abi}{os.sep}" in path:
+                return path
+    return candidates[0]
+
+
+def symbolize_elf(path, address):
+    # return ["function at file:line", ...], inlined frames first
+    if shutil.which("llvm-symbolizer"):
+        err_code, output = exec_command(f"llvm-symbolizer --obj={shlex.quote(path)} --inlines --demangle 0x{address}")
+    else:
+        err_code, output = exec_command(f"addr2line -f -C -i -e {shlex.quote(path)} 0x{address}")
+    if err_code != 0:
+        return []
+    lines = [x for x in output.splitlines() if x.strip()]
+    frames = []
+    for function, location in zip(lines[0::2], lines[1::2]):
+        if function != "??":
+            frames.append(f"{function} at {location}")
+    return frames
+
+
+def symbolize_apple(path, arch, load_address, address):
+    err_code, output = exec_command(
+        f"atos -arch {arch} -o {shlex.quote(path)} -l 0x{load_address} -i 0x{address}")
+    if err_code != 0:
+        return []
+    return [x.strip() for x in output.splitlines() if x.strip() and not x.strip().startswith("0x")]
+
+
+def find_apple_symbol_file(index, module):
+    # the dwarf of foo.framework.dSYM is Contents/Resources/DWARF/foo
+    candidates = index.get(module, [])
+    dwarfs = [x for x in candidates if f"{os.sep}DWARF{os.sep}" in x]
+    return (dwarfs or candidates or [None])[0]
+
+
+def symbolicate(text, index, readelf="readelf") -> tuple:
+    # return (symbolicated text, resolved frames, total frames)
+    arch_match = APPLE_CODE_TYPE_PATTERN.search(text)
+    arch = APPLE_ARCHS.get(arch_match.group("code_type"), "arm64") if arch_match else "arm64"
+    output = []
+    resolved = 0
+    total = 0
+    for line in text.splitlines():
+        frames = []
+        match = APPLE_FRAME_PATTERN.match(line.strip())
+        if match:
+            total += 1
+            path = find_apple_symbol_file(index, match.group("module"))
+            if path:
+                frames = symbolize_apple(path, arch, match.group("load_address"), match.group("address"))
+        else:
+            match = TOMBSTONE_FRAME_PATTERN.search(line) or BACKTRACE_FRAME_PATTERN.search(line)
+            if match:
+                total += 1
+                build_id = match.groupdict().get("build_id")
+                path = find_symbol_file(index, match.group("module"), build_id, readelf)
+                if path:
+                    frames = symbolize_elf(path, match.group("address"))
+        if not frames:
+            output.append(line)
+            continue
+        resolved += 1
+        indent = line[:len(line) - len(line.lstrip())]
+        output.append(line.rstrip())
+        output.extend([f"{indent}    {x}" for x in frames])
+    return "\n".join(output) + "\n", resolved, total
+
+
+def is_dump_syms_input(path) -> bool:
+    # the libs and debug files dump_syms converts, libfoo.so.1 included,
+    # the dwarf of dSYM bundles has no extension
+    name = os.path.basename(path)
+    if os.path.splitext(name)[1].lower() in DUMP_SYMS_EXTENSIONS or ".so." in name:
+        return True
+    return ".dSYM" in path and f"{os.sep}DWARF{os.sep}" in path
+
+
+def make_breakpad_symbol_store(index, store_dir, dump_syms="dump_syms") -> int:
+    # write <store>/<name>/<debug id>/<name>.sym of the libs and existing
+    # .sym files, the layout minidump processors look up by debug id
+    count = 0
+    for name, paths in index.items():
+        for path in paths:
+            if name.endswith(".sym"):
+                with open(path, "r", errors="replace") as f:
+                    content = f.read()
+            elif not is_dump_syms_input(path):
+                continue
+            else:
+                err_code, content = exec_command(f"{dump_syms} {shlex.quote(path)}")
+                if err_code != 0:
+                    continue
+            # MODULE <os> <arch> <debug id> <name>
+            fields = content.split("\n", 1)[0].split()
+            if len(fields) < 5 or fields[0] != "MODULE":
+                continue
+            module_name = " ".join(fields[4:])
+            sym_dir = os.path.join(store_dir, module_name, fields[3])
+            os.makedirs(sym_dir, exist_ok=True)
+            sym_name = os.path.splitext(module_name)[0] + ".sym" if module_name.endswith(".pdb") else module_name + ".sym"
+            with open(os.path.join(sym_dir, sym_name), "w") as f:
+                f.write(content)
+            count += 1
+    return count
@@ -0,0 +1,70 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import shlex
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.cmd.cmd_util import exec_command
+from utils.compile_db.compile_db_util import get_entry_arguments
+from utils.compile_db.compile_db_util import get_entry_output
+from utils.compile_db.compile_db_util import strip_output_arguments
+
+
+def parse_make_deps(content, directory) -> list:
+    # "a.o: a.cpp a.h \\\n b.h" -> [a.cpp, a.h, b.h]
+    content = content.replace("\\\n", " ")
+    deps = []
+    for line in content.splitlines():
+        if ":" not in line or line.startswith("#"):
+            continue
+        # skip the target, keep windows drive letters like C:/ in deps
+        target_end = line.find(": ")
+        if target_end < 0:
+            target_end = line.rfind(":")
+        for dep in line[target_end + 1:].split():
+            deps.append(os.path.normpath(os.path.join(directory, dep)))
+    return deps
+
+
+def get_entry_deps(entry) -> list:
+    # prefer the .d file of the last build, otherwise ask the compiler
+    output = get_entry_output(entry)
+    if output:
+        for dep_file in [output + ".d", os.path.splitext(output)[0] + ".d"]:
+            if os.path.isfile(dep_file):
+                with open(dep_file, "r", errors="replace") as f:
+                    return parse_make_deps(f.read(), entry["directory"])
+    arguments = strip_output_arguments(get_entry_arguments(entry)) + ["-MM"]
+    cmd = f"cd {shlex.quote(entry['directory'])} && " + " ".join([shlex.quote(x) for x in arguments])
+    err_code, output = exec_command(cmd)
+    if err_code != 0:
+        return []
+    return parse_make_deps(output, entry["directory"])
+
+
+def build_header_graph(entries, project_dir) -> dict:
+    # {header: [source, ...]} of headers inside the project dir
+    project_dir = os.path.abspath(project_dir)
+    graph = {}
+    for entry in entries:
+        for dep in get_entry_deps(entry):
+            if dep == entry["file"] or not dep.startswith(project_dir + os.sep):
+                continue
+            graph.setdefault(dep, []).append(entry["file"])
+    return graph
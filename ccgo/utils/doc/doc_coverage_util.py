@@ -0,0 +1,148 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+
+HEADER_EXTENSIONS = [".h", ".hh", ".hpp", ".hxx"]
+# class Foo, struct FOO_API Foo : public Bar, enum class Foo
+TYPE_PATTERN = re.compile(
+    r"^(?:template\s*<.*>\s*)?(class|struct|union|enum(?:\s+class)?)\s+(?:[A-Z0-9_]+\s+)*(\w+)\s*(?:final\s*)?(?::[^;]*)?(\{.*)?$"
+)
+# int foo(, static const std::string& Foo::bar(, ~Foo(, operator==(
+FUNCTION_PATTERN = re.compile(
+    r"^(?!(?:return|if|for|while|switch|case|else|do|using|typedef|friend|namespace|"
+    r"static_assert|delete|new|throw|public|private|protected)\b)"
+    r"(?:[\w:<>,\*&\[\]]+\s+|[\w:<>,\*&\[\]]+(?=[\*&]))*[\*&]*\s*(~?\w+|operator\s*[^\s(]+)\s*\("
+)
+ACCESS_PATTERN = re.compile(r"^(public|private|protected)\s*:")
+TEMPLATE_LINE_PATTERN = re.compile(r"^template\s*<.*>\s*$")
+NAMESPACE_PATTERN = re.compile(r"^(?:inline\s+)?namespace\b")
+STRING_PATTERN = re.compile(r'"(?:\\.|[^"\\])*"|\'(?:\\.|[^\'\\])*\'')
+
+
+def find_headers(include_dir) -> list:
+    headers = []
+    for root, dirs, files in os.walk(include_dir):
+        dirs.sort()
+        for file in sorted(files):
+            if os.path.splitext(file)[1] in HEADER_EXTENSIONS:
+                headers.append(os.path.join(root, file))
+    return headers
+
+
+def strip_code(line) -> str:
+    # drop strings and trailing comments so braces are counted right
+    code = STRING_PATTERN.sub('""', line)
+    code = code.split("//")[0]
+    return re.sub(r"/\*.*?\*/", "", code)
+
+
+def collect_symbols(header_path) -> list:
+    # return [{"name", "kind", "line", "documented"}] of public symbols
+    with open(header_path, "r", errors="replace") as f:
+        lines = f.readlines()
+    symbols = []
+    # stack of [kind, access], kind is namespace, class or body
+    scopes = []
+    documented = False
+    in_block_comment = False
+    in_macro = False
+    paren_depth = 0
+    pending_kind = None
+    for lineno, raw_line in enumerate(lines, 1):
+        line = raw_line.strip()
+        if in_block_comment:
+            if "*/" in line:
+                in_block_comment = False
+                documented = True
+            continue
+        if in_macro:
+            in_macro = line.endswith("\\")
+            continue
+        if not line:
+            documented = False
+            continue
+        if line.startswith("//"):
+            documented = True
+            continue
+        if line.startswith("/*"):
+            if "*/" in line:
+                documented = True
+            else:
+                in_block_comment = True
+            continue
+        if line.startswith("#"):
+            in_macro = line.endswith("\\")
+            documented = False
+            continue
+        code = strip_code(line).strip()
+        top = scopes[-1] if scopes else ["namespace", "public"]
+        line_kind = None
+        line_access = "public"
+        if paren_depth == 0 and top[0] in ["namespace", "class"]:
+            access = ACCESS_PATTERN.match(code)
+            if access:
+                top[1] = access.group(1)
+                documented = False
+                continue
+            if TEMPLATE_LINE_PATTERN.match(code):
+                # the doc comment is above the template line
+                continue
+            is_public = top[1] == "public"
+            type_match = TYPE_PATTERN.match(code)
+            function_match = FUNCTION_PATTERN.match(code)
+            if NAMESPACE_PATTERN.match(code):
+                line_kind = "namespace"
+            elif type_match and (not code.endswith(";") or "{" in code):
+                kind = type_match.group(1)
+                line_kind = "body" if kind.startswith("enum") else "class"
+                line_access = "private" if kind == "class" else "public"
+                if is_public:
+                    symbols.append({"name": type_match.group(2), "kind": kind.split()[0],
+                                    "line": lineno, "documented": documented})
+            elif function_match and not function_match.group(1).isupper():
+                line_kind = "body"
+                if is_public:
+                    symbols.append({"name": function_match.group(1), "kind": "function",
+                                    "line": lineno, "documented": documented})
+        paren_depth += code.count("(") - code.count(")")
+        for ch in code:
+            if ch == "{":
+                if line_kind:
+                    scopes.append([line_kind, line_access])
+                elif pending_kind:
+                    scopes.append(list(pending_kind))
+                else:
+                    scopes.append(["body", "public"])
+                line_kind = pending_kind = None
+            elif ch == "}" and scopes:
+                scopes.pop()
+        if line_kind and not code.endswith(";") and "{" not in code:
+            # the brace is on the next line
+            pending_kind = (line_kind, line_access)
+        elif code.endswith(";"):
+            pending_kind = None
+        documented = False
+    return symbols
+
+
+def compute_coverage(include_dir) -> list:
+    # return [{"file", "dir", "symbols"}] of every header
+    reports = []
+    for header in find_headers(include_dir):
+        relative_path = os.path.relpath(header, include_dir)
+        reports.append({
+            "file": relative_path,
+            "dir": os.path.dirname(relative_path) or ".",
+            "symbols": collect_symbols(header),
+        })
+    return reports
@@ -0,0 +1,58 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import re
+
+# ```cpp, ```c++ run, ```cc ignore
+FENCE_PATTERN = re.compile(r"^\s*```\s*(cpp|c\+\+|cc|cxx)\b\s*(.*)$", re.IGNORECASE)
+FENCE_END_PATTERN = re.compile(r"^\s*```\s*$")
+CODE_BEGIN_PATTERN = re.compile(r"^\s*[@\\]code\b")
+CODE_END_PATTERN = re.compile(r"^\s*[@\\]endcode\b")
+# leading "*", "///" or "//!" of doc comment lines
+COMMENT_PREFIX_PATTERN = re.compile(r"^\s*(?:///?!?|\*(?!/))\s?")
+
+
+def extract_markdown_snippets(path) -> list:
+    # return [{"file", "line", "code", "flags"}] of fenced c++ blocks
+    with open(path, "r", errors="replace") as f:
+        lines = f.read().splitlines()
+    return _extract_snippets(path, lines, strip_comment=False)
+
+
+def extract_header_snippets(path) -> list:
+    # fenced blocks and @code/@endcode blocks inside doc comments
+    with open(path, "r", errors="replace") as f:
+        lines = f.read().splitlines()
+    return _extract_snippets(path, lines, strip_comment=True)
+
+
+def _extract_snippets(path, lines, strip_comment) -> list:
+    snippets = []
+    current = None
+    for lineno, raw_line in enumerate(lines, 1):
+        line = COMMENT_PREFIX_PATTERN.sub("", raw_line, count=1) if strip_comment else raw_line
+        if current is None:
+            fence = FENCE_PATTERN.match(line)
+            if fence:
+                current = {"file": path, "line": lineno + 1, "code": [],
+                           "flags": fence.group(2).split(), "end": FENCE_END_PATTERN}
+            elif strip_comment and CODE_BEGIN_PATTERN.match(line):
+                current = {"file": path, "line": lineno + 1, "code": [],
+                           "flags": [], "end": CODE_END_PATTERN}
+            continue
+        if current["end"].match(line):
+            current.pop("end")
+            current["code"] = "\n".join(current["code"]) + "\n"
+            snippets.append(current)
+            current = None
+            continue
+        current["code"].append(line)
+    return snippets
@@ -0,0 +1,87 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+# values of these keys are masked when printed
+SECRET_KEY_PATTERN = re.compile(r"TOKEN|SECRET|PASSWORD|PASSWD|CREDENTIAL|PRIVATE|_KEY$|^KEY$", re.IGNORECASE)
+MASKED_VALUE = "******"
+ENV_KEY_PATTERN = re.compile(r"^[A-Za-z_][A-Za-z0-9_]*$")
+
+
+def parse_env_line(line):
+    # return (key, value) of KEY=VALUE or export KEY="VALUE", None for comments
+    line = line.strip()
+    if not line or line.startswith("#"):
+        return None
+    if line.startswith("export "):
+        line = line[len("export "):].strip()
+    key, sep, value = line.partition("=")
+    key = key.strip()
+    if not sep or not ENV_KEY_PATTERN.match(key):
+        raise ValueError(f"invalid environment variable: {line}")
+    value = value.strip()
+    if len(value) >= 2 and value[0] == value[-1] and value[0] in ["'", '"']:
+        value = value[1:-1]
+    return key, value
+
+
+def parse_env_file(path) -> dict:
+    env = {}
+    with open(path, "r") as f:
+        for lineno, line in enumerate(f, 1):
+            try:
+                item = parse_env_line(line)
+            except ValueError as e:
+                raise ValueError(f"{path}:{lineno}: {e}")
+            if item:
+                env[item[0]] = item[1]
+    return env
+
+
+def load_env(env_file=None, env_args=None) -> dict:
+    # --env values override those of --env-file
+    env = {}
+    if env_file:
+        env.update(parse_env_file(env_file))
+    for arg in env_args or []:
+        item = parse_env_line(arg)
+        if item:
+            env[item[0]] = item[1]
+    return env
+
+
+def is_secret_key(key) -> bool:
+    return SECRET_KEY_PATTERN.search(key) is not None
+
+
+def mask_env_value(key, value) -> str:
+    return MASKED_VALUE if is_secret_key(key) else value
+
+
+def mask_env_args(env_args) -> list:
+    masked = []
+    for arg in env_args or []:
+        key, sep, value = arg.partition("=")
+        masked.append(f"{key}{sep}{mask_env_value(key.strip(), value)}")
+    return masked
+
+
+def apply_env(env):
+    # child processes like cmake, gradle and test runners inherit it
+    for key in sorted(env):
+        logger.info(f"  {key}={mask_env_value(key, env[key])}")
+    os.environ.update(env)
@@ -0,0 +1,389 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.error.exit_code import ExitCode
+
+# E00NN is the error of exit code NN, shown on failures and explained by
+# `ccgo explain`
+ERROR_CODES = {
+    ExitCode.GENERAL_ERROR: {
+        "title": "unexpected error",
+        "description": "The command failed in a way that has no better category.",
+        "causes": [
+            "a build tool or script failed before it could report why",
+            "a bug of ccgo",
+        ],
+        "fixes": [
+            "run again with `--log-level debug` to see the commands and their output",
+            "report it at https://github.com/zhlinh/ccgo/issues with the log",
+        ],
+    },
+    ExitCode.USAGE_ERROR: {
+        "title": "invalid command line",
+        "description": "The arguments or options do not make sense together.",
+        "causes": [
+            "a misspelled target or option",
+            "an option used with a target it does not apply to, like --filter without `build tests`",
+        ],
+        "fixes": [
+            "check `ccgo <command> --help` for the targets and options",
+        ],
+    },
+    ExitCode.CONFIG_ERROR: {
+        "title": "invalid project files, options or environment",
+        "description": "ccgo could not find or read a file or setting the command needs.",
+        "causes": [
+            "running ccgo outside the project dir created by `ccgo lib create`, so build_<platform>.py is missing",
+            "a --toolchain-file, --env-file or compile_commands.json that does not exist",
+            "a malformed .ccgo/toolchains.toml or dotenv file",
+            "a --toolchain that is not registered as [toolchains.<name>] in .ccgo/toolchains.toml",
+            "GITHUB_TOKEN or the github repo can not be found for publishing",
+        ],
+        "fixes": [
+            "cd into the project dir that contains the build_*.py scripts",
+            "check the paths given on the command line",
+            "build with CMAKE_EXPORT_COMPILE_COMMANDS=ON before the analyze commands",
+        ],
+    },
+    ExitCode.TOOLCHAIN_MISSING: {
+        "title": "required toolchain or tool not installed",
+        "description": "A compiler, sdk or tool the command runs was not found.",
+        "causes": [
+            "the android sdk or ndk is not installed, or ANDROID_HOME is not set",
+            "the Xcode version of --xcode is not in /Applications",
+            "icecc, distcc, perf, sdkmanager or minidump-stackwalk is not in PATH",
+        ],
+        "fixes": [
+            "see `ccgo toolchain list` for the toolchains found on this host",
+            "install the ndk with `ccgo toolchain install ndk <version>`",
+            "pin an installed ndk or xcode with `ccgo toolchain use`",
+        ],
+    },
+    ExitCode.COMPILE_ERROR: {
+        "title": "build or compile failed",
+        "description": "The build script of a platform exited with an error.",
+        "causes": [
+            "a compile or link error of the project sources",
+            "the cmake configure failed, often for a wrong toolchain",
+        ],
+        "fixes": [
+            "read the compiler output above, or write it to a file with `--log-file`",
+            "retry only the failed platforms with `ccgo build all --resume`",
+        ],
+    },
+    ExitCode.TEST_FAILURE: {
+        "title": "tests failed",
+        "description": "The tests were built but some of them failed.",
+        "causes": [
+            "a regression of the code under test",
+        ],
+        "fixes": [
+            "rerun the failed tests only with `ccgo build tests --filter <name>`",
+        ],
+    },
+    ExitCode.PACKAGING_ERROR: {
+        "title": "packaging failed",
+        "description": "The sdk archives could not be read, merged or written.",
+        "causes": [
+            "archives of different versions or revisions given to `ccgo package merge`",
+            "the same file with different contents in the merged archives",
+            "a corrupted zip",
+        ],
+        "fixes": [
+            "merge the archives of the same revision, or pass --allow-inconsistent",
+            "compare the archives with `ccgo diff`",
+        ],
+    },
+    ExitCode.NETWORK_ERROR: {
+        "title": "network error",
+        "description": "A remote server could not be reached or refused the request.",
+        "causes": [
+            "no network, or a proxy is needed",
+            "a corporate CA certificate is not trusted",
+            "an expired or missing token",
+        ],
+        "fixes": [
+            "set CCGO_PROXY and CCGO_CA_BUNDLE, see the Network section of the README",
+            "raise CCGO_NET_RETRIES or CCGO_NET_TIMEOUT for flaky networks",
+        ],
+    },
+    ExitCode.CHECK_FAILURE: {
+        "title": "check found issues",
+        "description": "A check command ran successfully and found issues in the project.",
+        "causes": [
+            "doc coverage below the threshold or failing doc snippets",
+            "duplicate symbols, missing hardening or third-party licenses",
+            "an archive which does not match its checksum or provenance",
+        ],
+        "fixes": [
+            "fix the issues listed above, they are the report of the check",
+        ],
+    },
+    ExitCode.PUBLISH_ERROR: {
+        "title": "publishing failed",
+        "description": "The library could not be published to the repository.",
+        "causes": [
+            "missing or wrong credentials of the maven repository",
+            "the version is published already",
+        ],
+        "fixes": [
+            "check the gradle output above",
+            "bump the version with `ccgo tag`",
+        ],
+    },
+}
+
+
+# ESSNN is the specific error NN of subsystem SS, the process still exits
+# with the exit code of its category:
+# 01 toolchains, 02 packaging, 03 builds, 04 tests, 05 downloads,
+# 06 publishing, 07 checks
+SUBSYSTEM_ERROR_CODES = {
+    "E0101": {
+        "exit_code": ExitCode.TOOLCHAIN_MISSING,
+        "title": "android ndk or sdk not found",
+        "description": "The ndk of the requested version, the android sdk or its sdkmanager is not installed.",
+        "causes": [
+            "ANDROID_HOME is not set and the sdk is not in the default dir of android studio",
+            "the pinned ndk was removed from the sdk",
+        ],
+        "fixes": [
+            "install the ndk with `ccgo toolchain install ndk <version>`",
+            "see `ccgo toolchain list ndk` for the installed ndks",
+        ],
+    },
+    "E0102": {
+        "exit_code": ExitCode.TOOLCHAIN_MISSING,
+        "title": "xcode version not found",
+        "description": "No installed Xcode.app in /Applications matches the version of --xcode or the [xcode] pin.",
+        "causes": [
+            "the pinned xcode was removed or renamed",
+        ],
+        "fixes": [
+            "see `ccgo toolchain list xcode` for the installed versions",
+            "pin an installed one with `ccgo toolchain use xcode <version>`",
+        ],
+    },
+    "E0103": {
+        "exit_code": ExitCode.TOOLCHAIN_MISSING,
+        "title": "tool can not read the binaries of the target",
+        "description": "nm, readelf, objdump or otool of the host failed to read a binary built for another target.",
+        "causes": [
+            "the libs are built by the ndk or a cross toolchain the host tools do not understand",
+        ],
+        "fixes": [
+            "pass the tools of the target toolchain, like --nm llvm-nm or --readelf llvm-readelf",
+        ],
+    },
+    "E0104": {
+        "exit_code": ExitCode.TOOLCHAIN_MISSING,
+        "title": "tool not found in PATH",
+        "description": "A tool the command runs, like a profiler, analyzer or dump_syms, is not installed.",
+        "causes": [
+            "the tool is not installed or not in PATH",
+        ],
+        "fixes": [
+            "install the tool named above, or pass its path with the option of the command",
+        ],
+    },
+    "E0201": {
+        "exit_code": ExitCode.PACKAGING_ERROR,
+        "title": "archive can not be read",
+        "description": "An sdk archive is missing, truncated or not a zip.",
+        "causes": [
+            "the build did not finish, or the download was cut off",
+        ],
+        "fixes": [
+            "build or download the archive again",
+        ],
+    },
+    "E0202": {
+        "exit_code": ExitCode.PACKAGING_ERROR,
+        "title": "files conflict between archives",
+        "description": "The archives to merge have the same file with different contents.",
+        "causes": [
+            "a header or lib built differently for two platforms at the same path",
+        ],
+        "fixes": [
+            "build all the archives from the same revision and flags",
+        ],
+    },
+    "E0203": {
+        "exit_code": ExitCode.PACKAGING_ERROR,
+        "title": "version conflict between archives",
+        "description": "The build_info.json of the archives to merge differ in name, version or revision.",
+        "causes": [
+            "archives of an older build left in bin",
+        ],
+        "fixes": [
+            "remove the old archives and build all platforms from the same revision",
+        ],
+    },
+    "E0301": {
+        "exit_code": ExitCode.CONFIG_ERROR,
+        "title": "build script not found",
+        "description": "build_<platform>.py of the platform is not in the current dir.",
+        "causes": [
+            "running ccgo outside the project dir created by `ccgo lib create`",
+        ],
+        "fixes": [
+            "cd into the project dir that contains the build_*.py scripts",
+        ],
+    },
+    "E0302": {
+        "exit_code": ExitCode.CONFIG_ERROR,
+        "title": "custom toolchain not registered",
+        "description": "The --toolchain is not registered as [toolchains.<name>] in .ccgo/toolchains.toml.",
+        "causes": [
+            "a misspelled toolchain name",
+        ],
+        "fixes": [
+            "see `ccgo toolchain list` for the registered toolchains",
+        ],
+    },
+    "E0303": {
+        "exit_code": ExitCode.COMPILE_ERROR,
+        "title": "platform build failed",
+        "description": "The build script of a platform failed to configure or compile.",
+        "causes": [
+            "a compile error of the code",
+            "the cmake configure failed, often for a wrong toolchain",
+        ],
+        "fixes": [
+            "read the compiler output above, the first error is usually the cause",
+            "retry the failed platforms with `ccgo build all --resume`",
+        ],
+    },
+    "E0401": {
+        "exit_code": ExitCode.TEST_FAILURE,
+        "title": "test cases failed",
+        "description": "The tests were built and ran, and some of them failed.",
+        "causes": [
+            "a regression of the code or a flaky test",
+        ],
+        "fixes": [
+            "rerun the failed tests with `ccgo build tests --filter <name>`",
+        ],
+    },
+    "E0501": {
+        "exit_code": ExitCode.NETWORK_ERROR,
+        "title": "download failed",
+        "description": "A release or archive could not be downloaded completely.",
+        "causes": [
+            "a proxy or firewall blocks the connection",
+            "the rate limit of the github api without a token",
+        ],
+        "fixes": [
+            "set CCGO_PROXY and CCGO_CA_BUNDLE, see the Network section of the README",
+            "set GITHUB_TOKEN to raise the rate limit",
+        ],
+    },
+    "E0601": {
+        "exit_code": ExitCode.CONFIG_ERROR,
+        "title": "github token not set",
+        "description": "Publishing a github release needs a token with access to the repository.",
+        "causes": [
+            "GITHUB_TOKEN or GH_TOKEN is not set",
+        ],
+        "fixes": [
+            "set GITHUB_TOKEN, in github actions from secrets.GITHUB_TOKEN",
+        ],
+    },
+    "E0701": {
+        "exit_code": ExitCode.CHECK_FAILURE,
+        "title": "duplicate symbols",
+        "description": "The same symbol is defined strongly in objects of more than one static lib.",
+        "causes": [
+            "a third-party lib vendored by two modules",
+        ],
+        "fixes": [
+            "link the shared code from one module only",
+        ],
+    },
+    "E0702": {
+        "exit_code": ExitCode.CHECK_FAILURE,
+        "title": "missing hardening",
+        "description": "A required security mitigation is not applied to the binary.",
+        "causes": [
+            "the binary was built without `ccgo build --hardening`",
+        ],
+        "fixes": [
+            "build again with --hardening",
+        ],
+    },
+    "E0703": {
+        "exit_code": ExitCode.CHECK_FAILURE,
+        "title": "third-party license missing or unknown",
+        "description": "A dependency has no license file, or its license is none of the known ones.",
+        "causes": [
+            "a vendored dependency copied without its license file",
+        ],
+        "fixes": [
+            "add the license file to the dir of the dependency, and review unknown licenses by hand",
+        ],
+    },
+    "E0704": {
+        "exit_code": ExitCode.CHECK_FAILURE,
+        "title": "archive does not match its checksum or provenance",
+        "description": "The sha256 of an archive differs from the published checksum or its provenance.",
+        "causes": [
+            "the archive was modified or replaced after it was built",
+        ],
+        "fixes": [
+            "download the archive again from a trusted source",
+        ],
+    },
+}
+
+
+class ErrorCodeExit(SystemExit):
+    # sys.exit with the exit code of the category of a specific error code
+    def __init__(self, error_code):
+        super().__init__(SUBSYSTEM_ERROR_CODES[error_code]["exit_code"])
+        self.error_code = error_code
+
+
+def exit_with_error(error_code):
+    raise ErrorCodeExit(error_code)
+
+
+def format_error_code(exit_code) -> str:
+    return f"E{exit_code:04d}"
+
+
+def parse_error_code(text):
+    # "E0004", "e4" and "4" are all exit code 4, None if not known
+    text = text.strip().upper().lstrip("E")
+    if not text.isdigit() or int(text) not in ERROR_CODES:
+        return None
+    return int(text)
+
+
+def find_error(text):
+    # return (error code, error) of a subsystem error code like E0203 or
+    # of an exit code, (None, None) if not known
+    code = text.strip().upper()
+    if code in SUBSYSTEM_ERROR_CODES:
+        return code, SUBSYSTEM_ERROR_CODES[code]
+    exit_code = parse_error_code(text)
+    if exit_code is None:
+        return None, None
+    return format_error_code(exit_code), ERROR_CODES[exit_code]
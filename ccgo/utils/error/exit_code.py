@@ -0,0 +1,35 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+# Stable exit codes of all commands, so CI can tell infrastructure
+# failures from failures of the code being built. Never renumber them.
+class ExitCode:
+    SUCCESS = 0
+    # unexpected failure without a better category
+    GENERAL_ERROR = 1
+    # invalid command line, same as argparse
+    USAGE_ERROR = 2
+    # invalid project files, options or environment
+    CONFIG_ERROR = 3
+    # required compiler, sdk or tool is not installed
+    TOOLCHAIN_MISSING = 4
+    # the build scripts or compiler failed
+    COMPILE_ERROR = 5
+    # tests were built but failed
+    TEST_FAILURE = 6
+    # archives or packages can not be produced
+    PACKAGING_ERROR = 7
+    # remote servers can not be reached or refused the request
+    NETWORK_ERROR = 8
+    # a check like doc coverage or duplicate symbols found issues
+    CHECK_FAILURE = 9
+    # publishing to a repository failed
+    PUBLISH_ERROR = 10
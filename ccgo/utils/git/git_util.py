@@ -0,0 +1,70 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.cmd.cmd_util import exec_command
+
+
+def get_last_tag():
+    # return None if there is no tag reachable from HEAD
+    err_code, output = exec_command("git describe --tags --abbrev=0")
+    if err_code != 0:
+        return None
+    return output.strip()
+
+
+def get_commit_subjects(since_tag=None) -> list:
+    rev_range = f"{since_tag}..HEAD" if since_tag else "HEAD"
+    err_code, output = exec_command(f"git log {rev_range} --pretty=format:%s")
+    if err_code != 0:
+        return []
+    return [line for line in output.splitlines() if line.strip()]
+
+
+def get_head_revision():
+    err_code, output = exec_command("git rev-parse HEAD")
+    if err_code != 0:
+        return None
+    return output.strip()
+
+
+def get_toplevel_dir():
+    # None outside of a git work tree
+    err_code, output = exec_command("git rev-parse --show-toplevel")
+    if err_code != 0 or not output.strip():
+        return None
+    return os.path.normpath(output.strip())
+
+
+def is_tag_exists(tag_name) -> bool:
+    err_code, output = exec_command(f"git rev-parse -q --verify refs/tags/{tag_name}")
+    return err_code == 0
+
+
+def is_work_tree_clean() -> bool:
+    err_code, output = exec_command("git status --porcelain")
+    return err_code == 0 and not output.strip()
+
+
+def get_remote_url(remote="origin"):
+    err_code, output = exec_command(f"git remote get-url {remote}")
+    if err_code != 0:
+        return None
+    return output.strip()
@@ -0,0 +1,113 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import sys
+import urllib.parse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.git.git_util import get_remote_url
+from utils.net.http_util import http_request
+from utils.net.http_util import http_request_json
+
+GITHUB_API_URL = "https://api.github.com"
+# uploads and downloads may take long for big SDK archives
+UPLOAD_TIMEOUT_SECOND = 3600
+RELEASES_PER_PAGE = 100
+
+
+def get_github_token():
+    return os.environ.get("GITHUB_TOKEN") or os.environ.get("GH_TOKEN")
+
+
+def get_origin_repo():
+    # parse owner/name from git@github.com:owner/name.git or https url
+    url = get_remote_url()
+    if not url:
+        return None
+    match = re.search(r"github\.com[:/]([^/]+/[^/]+?)(\.git)?$", url)
+    return match.group(1) if match else None
+
+
+class GitHubClient:
+    def __init__(self, repo, token=None):
+        self.repo = repo
+        self.token = token
+
+    def headers(self) -> dict:
+        headers = {
+            "Accept": "application/vnd.github+json",
+            "User-Agent": "ccgo",
+        }
+        if self.token:
+            headers["Authorization"] = f"Bearer {self.token}"
+        return headers
+
+    def get_release_by_tag(self, tag):
+        url = f"{GITHUB_API_URL}/repos/{self.repo}/releases/tags/{tag}"
+        status, release = http_request_json("GET", url, self.headers())
+        if status != 404:
+            return status, release
+        # drafts are not found by tag, only in the list of releases
+        page = 1
+        while True:
+            url = f"{GITHUB_API_URL}/repos/{self.repo}/releases?per_page={RELEASES_PER_PAGE}&page={page}"
+            status, releases = http_request_json("GET", url, self.headers())
+            if status != 200:
+                return status, releases
+            for release in releases:
+                if release.get("tag_name") == tag:
+                    return 200, release
+            if len(releases) < RELEASES_PER_PAGE:
+                return 404, {}
+            page += 1
+
+    def create_release(self, tag, name, body, draft, prerelease):
+        url = f"{GITHUB_API_URL}/repos/{self.repo}/releases"
+        payload = {
+            "tag_name": tag, "name": name, "body": body,
+            "draft": draft, "prerelease": prerelease,
+        }
+        return http_request_json("POST", url, self.headers(), payload)
+
+    def update_release(self, release_id, draft=None, prerelease=None):
+        # only change the marks given, None keeps the current one
+        url = f"{GITHUB_API_URL}/repos/{self.repo}/releases/{release_id}"
+        payload = {k: v for k, v in [("draft", draft), ("prerelease", prerelease)] if v is not None}
+        return http_request_json("PATCH", url, self.headers(), payload)
+
+    def delete_asset(self, asset_id):
+        url = f"{GITHUB_API_URL}/repos/{self.repo}/releases/assets/{asset_id}"
+        return http_request("DELETE", url, self.headers())
+
+    def upload_asset(self, release, file_path):
+        # upload_url is like https://uploads.github.com/...{?name,label}
+        upload_url = release["upload_url"].split("{")[0]
+        name = urllib.parse.quote(os.path.basename(file_path))
+        headers = self.headers()
+        headers["Content-Type"] = "application/octet-stream"
+        with open(file_path, "rb") as f:
+            data = f.read()
+        return http_request("POST", f"{upload_url}?name={name}", headers, data,
+                            UPLOAD_TIMEOUT_SECOND)
+
+    def download_asset(self, asset):
+        # the api url of an asset also works for private repos
+        headers = self.headers()
+        headers["Accept"] = "application/octet-stream"
+        return http_request("GET", asset["url"], headers, None, UPLOAD_TIMEOUT_SECOND)
@@ -0,0 +1,211 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import sys
+import shutil
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.cmd.cmd_util import exec_command
+from utils.config.config_header_util import GENERATED_DIR
+
+GCC_LIKE_CFLAGS = ["-fstack-protector-strong"]
+# fortify source warns in every file of the -O0 debug builds, so it is
+# defined by a header forced into the compile, only when optimized
+FORTIFY_HEADER_NAME = "ccgo_fortify.h"
+FORTIFY_HEADER = """// generated by `ccgo build --hardening`, do not edit
+#if defined(__OPTIMIZE__) && !defined(_FORTIFY_SOURCE)
+#define _FORTIFY_SOURCE 2
+#endif
+"""
+# flags of each platform, and the mitigations left out with the reason
+HARDENING_FLAGS = {
+    "android": {
+        "cflags": GCC_LIKE_CFLAGS,
+        "fortify": True,
+        "ldflags": ["-Wl,-z,relro", "-Wl,-z,now", "-Wl,-z,noexecstack"],
+        "skipped": ["cfi: -fsanitize=cfi needs lto and visibility of the whole project"],
+    },
+    "linux": {
+        "cflags": GCC_LIKE_CFLAGS,
+        "fortify": True,
+        "ldflags": ["-Wl,-z,relro", "-Wl,-z,now", "-Wl,-z,noexecstack"],
+        "skipped": ["cfi: -fsanitize=cfi needs clang with lto"],
+    },
+    "ios": {
+        "cflags": GCC_LIKE_CFLAGS,
+        "fortify": True,
+        "ldflags": [],
+        "skipped": ["relro, nx: enforced by the apple platform"],
+    },
+    "macos": {
+        "cflags": GCC_LIKE_CFLAGS,
+        "fortify": True,
+        "ldflags": [],
+        "skipped": ["relro, nx: enforced by the apple platform"],
+    },
+    "windows": {
+        "cflags": ["/GS", "/guard:cf"],
+        "ldflags": ["/guard:cf", "/DYNAMICBASE", "/NXCOMPAT"],
+        "skipped": [],
+    },
+}
+HARDENING_FLAGS["tests"] = HARDENING_FLAGS["benches"] = HARDENING_FLAGS[
+    "macos" if sys.platform == "darwin" else "windows" if sys.platform == "win32" else "linux"]
+# fortify source only shows when fortified calls are used, and cfi is not
+# applied by HARDENING_FLAGS, so they are reported but not required
+REQUIRED_MITIGATIONS = ["stack_protector", "relro", "nx", "pie", "aslr", "guard_cf", "gs"]
+FORTIFY_SYMBOL_PATTERN = re.compile(r"\b_{2,3}\w+_chk\b")
+# instructions taking the address of a stack slot, like "lea -0x20(%rbp),%rdi"
+# or "mov %rsp,%rdi" of x86 and "add x0, sp, #0x10" of arm, the functions with
+# them are the ones -fstack-protector-strong puts a canary in. the frame
+# pointer setup like "mov %rsp,%rbp" or "mov x29, sp" is not one
+STACK_ADDRESS_PATTERN = re.compile(
+    r"\blea[lq]?\s+-?(?:0x[0-9a-f]+)?\(%[re]?[sb]p\)"
+    r"|\bmov[lq]?\s+%[re]?sp,\s*%(?![re]?bp\b)\w+"
+    r"|\b(?:add|mov)\s+(?!x29\b|r7\b|r11\b|fp\b)[xwr]\d+,\s*sp\b",
+    re.IGNORECASE)
+
+
+def get_fortify_header_path(platform) -> str:
+    return os.path.abspath(os.path.join(GENERATED_DIR, platform, "hardening", FORTIFY_HEADER_NAME))
+
+
+def write_fortify_header(platform) -> str:
+    header_path = get_fortify_header_path(platform)
+    os.makedirs(os.path.dirname(header_path), exist_ok=True)
+    with open(header_path, "w") as f:
+        f.write(FORTIFY_HEADER)
+    return header_path
+
+
+def get_hardening_env(platform, environ) -> dict:
+    # CFLAGS, CXXFLAGS and LDFLAGS initialize the flags of the cmake configure
+    flags = HARDENING_FLAGS.get(platform)
+    if not flags:
+        return {}
+    env = {}
+    cflags = list(flags["cflags"])
+    if flags.get("fortify"):
+        cflags.append(f"-include \"{get_fortify_header_path(platform)}\"")
+    for name, values in [("CFLAGS", cflags), ("CXXFLAGS", cflags), ("LDFLAGS", flags["ldflags"])]:
+        if values:
+            env[name] = " ".join([x for x in [environ.get(name)] + values if x])
+    return env
+
+
+def detect_binary_format(path):
+    with open(path, "rb") as f:
+        magic = f.read(8)
+    if magic.startswith(b"\x7fELF"):
+        return "elf"
+    if magic[:4] in [b"\xfe\xed\xfa\xce", b"\xce\xfa\xed\xfe", b"\xfe\xed\xfa\xcf", b"\xcf\xfa\xed\xfe", b"\xca\xfe\xba\xbe"]:
+        return "macho"
+    if magic.startswith(b"MZ"):
+        return "pe"
+    if magic.startswith(b"!<arch>"):
+        return "archive"
+    return None
+
+
+def run_tool(tool, arguments):
+    # a tool of another toolchain can not read the binary, the report
+    # would be made up of empty outputs
+    err_code, output = exec_command(f"{tool} {arguments}")
+    if err_code != 0:
+        raise RuntimeError(f"{tool} failed with exit code {err_code}")
+    return output
+
+
+def get_disassembly(tool, arguments):
+    err_code, output = exec_command(f"{tool} {arguments}")
+    return output if err_code == 0 else None
+
+
+def check_symbols(output, disassembly=None) -> dict:
+    # a binary without canaries is fine if no function has a stack buffer,
+    # without the disassembly it can not be told
+    if "__stack_chk_fail" in output:
+        stack_protector = "yes"
+    elif disassembly is not None and not STACK_ADDRESS_PATTERN.search(disassembly):
+        stack_protector = "n/a"
+    else:
+        stack_protector = "no"
+    return {
+        "stack_protector": stack_protector,
+        "fortify_source": "yes" if FORTIFY_SYMBOL_PATTERN.search(output) else "no",
+    }
+
+
+def check_elf(path, readelf, objdump) -> dict:
+    header = run_tool(readelf, f"-hW \"{path}\"")
+    segments = run_tool(readelf, f"-lW \"{path}\"")
+    dynamic = run_tool(readelf, f"-dW \"{path}\"")
+    symbols = run_tool(readelf, f"-sW --dyn-syms \"{path}\"")
+    is_executable = "INTERP" in segments
+    bind_now = "BIND_NOW" in dynamic or re.search(r"FLAGS.*\bNOW\b", dynamic) is not None
+    stack = re.search(r"GNU_STACK.*", segments)
+    result = check_symbols(symbols, get_disassembly(objdump, f"-d --no-show-raw-insn \"{path}\""))
+    result["relro"] = "full" if "GNU_RELRO" in segments and bind_now else "partial" if "GNU_RELRO" in segments else "no"
+    result["nx"] = "no" if not stack or "RWE" in stack.group(0) else "yes"
+    result["pie"] = ("yes" if "DYN" in header else "no") if is_executable else "n/a"
+    result["cfi"] = "yes" if "__cfi_check" in symbols or "__cfi_slowpath" in symbols else "no"
+    return result
+
+
+def check_macho(path) -> dict:
+    symbols = run_tool("nm", f"-a \"{path}\"")
+    header = run_tool("otool", f"-hv \"{path}\"")
+    result = check_symbols(symbols, get_disassembly("otool", f"-tV \"{path}\""))
+    result["pie"] = ("yes" if "PIE" in header else "no") if "EXECUTE" in header else "n/a"
+    return result
+
+
+def check_pe(path, objdump) -> dict:
+    headers = run_tool(objdump, f"-p \"{path}\"")
+    result = {
+        "nx": "yes" if "NX_COMPAT" in headers else "no",
+        "aslr": "yes" if "DYNAMIC_BASE" in headers else "no",
+        "guard_cf": "yes" if "GUARD_CF" in headers else "no",
+        "gs": "unknown",
+    }
+    if shutil.which("llvm-readobj"):
+        load_config = run_tool("llvm-readobj", f"--coff-load-config \"{path}\"")
+        cookie = re.search(r"SecurityCookie:\s*(0x[0-9A-Fa-f]+)", load_config)
+        result["gs"] = "yes" if cookie and int(cookie.group(1), 16) != 0 else "no"
+    return result
+
+
+def check_archive(path, objdump) -> dict:
+    # only the compile flags can be seen in the objects of a static lib
+    symbols = run_tool("nm", f"\"{path}\"")
+    return check_symbols(symbols, get_disassembly(objdump, f"-d --no-show-raw-insn \"{path}\""))
+
+
+def check_binary(path, readelf="readelf", objdump="objdump"):
+    # return (format, {mitigation: "yes"/"no"/"partial"/"full"/"n/a"/"unknown"})
+    binary_format = detect_binary_format(path)
+    if binary_format == "elf":
+        return binary_format, check_elf(path, readelf, objdump)
+    if binary_format == "macho":
+        return binary_format, check_macho(path)
+    if binary_format == "pe":
+        return binary_format, check_pe(path, objdump)
+    if binary_format == "archive":
+        return binary_format, check_archive(path, objdump)
+    return None, {}
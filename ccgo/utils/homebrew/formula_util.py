@@ -0,0 +1,93 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import zipfile
+
+LIB_EXTENSIONS = [".a", ".dylib"]
+
+
+def get_class_name(name) -> str:
+    # "foo-sdk" -> "FooSdk", the formula class of homebrew
+    return "".join([x.capitalize() for x in re.split(r"[^a-zA-Z0-9]+", name) if x])
+
+
+def get_archive_layout(path) -> dict:
+    # return {"include_dirs", "libs", "frameworks"} relative to the dir
+    # homebrew enters after unpacking, the single top-level dir if any
+    with zipfile.ZipFile(path) as archive:
+        names = [x for x in archive.namelist() if not x.startswith("__MACOSX/")]
+    top_dirs = set([x.split("/", 1)[0] for x in names])
+    if len(top_dirs) == 1 and all(["/" in x for x in names]):
+        prefix = list(top_dirs)[0] + "/"
+        names = [x[len(prefix):] for x in names if x != prefix]
+    include_dirs = set()
+    libs = set()
+    frameworks = set()
+    for name in names:
+        parts = name.split("/")
+        framework = next((i for i, x in enumerate(parts) if x.endswith(".framework")), None)
+        if framework is not None:
+            frameworks.add("/".join(parts[:framework + 1]))
+        elif "include" in parts[:-1]:
+            include_dirs.add("/".join(parts[:parts.index("include") + 1]))
+        elif os.path.splitext(name)[1] in LIB_EXTENSIONS and not name.endswith("/"):
+            libs.add(name)
+    return {"include_dirs": sorted(include_dirs), "libs": sorted(libs), "frameworks": sorted(frameworks)}
+
+
+def get_link_flags(libs) -> str:
+    names = sorted(set([re.sub(r"^lib", "", os.path.basename(x).split(".")[0]) for x in libs]))
+    return " ".join([f"-l{x}" for x in names])
+
+
+def render_formula(name, version, url, sha256, layout, description, homepage) -> str:
+    lines = [
+        f"class {get_class_name(name)} < Formula",
+        f"  desc \"{description}\"",
+        f"  homepage \"{homepage}\"",
+        f"  url \"{url}\"",
+        f"  version \"{version}\"",
+        f"  sha256 \"{sha256}\"",
+        "",
+        "  depends_on :macos",
+        "",
+        "  def install",
+    ]
+    lines += [f"    include.install Dir[\"{x}/*\"]" for x in layout["include_dirs"]]
+    lines += [f"    lib.install \"{x}\"" for x in layout["libs"]]
+    lines += [f"    frameworks.install \"{x}\"" for x in layout["frameworks"]]
+    if layout["libs"]:
+        lines += [
+            f"    (lib/\"pkgconfig/{name}.pc\").write <<~EOS",
+            "      prefix=#{opt_prefix}",
+            "      includedir=${prefix}/include",
+            "      libdir=${prefix}/lib",
+            "",
+            f"      Name: {name}",
+            f"      Description: {description}",
+            f"      Version: {version}",
+            f"      Libs: -L${{libdir}} {get_link_flags(layout['libs'])}",
+            "      Cflags: -I${includedir}",
+            "    EOS",
+        ]
+    lines += [
+        "  end",
+        "",
+        "  test do",
+    ]
+    if layout["libs"]:
+        lines.append(f"    system \"pkg-config\", \"--exists\", \"{name}\"")
+    else:
+        lines.append("    assert_predicate prefix, :exist?")
+    lines += ["  end", "end", ""]
+    return "\n".join(lines)
@@ -0,0 +1,63 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.i18n.zh_cn import MESSAGES as ZH_CN_MESSAGES
+
+DEFAULT_LOCALE = "en"
+# messages are keyed by their english text, missing ones fall back to it
+CATALOGS = {
+    "en": {},
+    "zh-CN": ZH_CN_MESSAGES,
+}
+LOCALES = list(CATALOGS.keys())
+LOCALE_ENV = "CCGO_LOCALE"
+
+_locale = DEFAULT_LOCALE
+
+
+def detect_locale() -> str:
+    # CCGO_LOCALE, then the posix locale variables, like zh_CN.UTF-8
+    if os.environ.get(LOCALE_ENV) in CATALOGS:
+        return os.environ[LOCALE_ENV]
+    for name in ["LC_ALL", "LC_MESSAGES", "LANG"]:
+        value = os.environ.get(name)
+        if not value:
+            continue
+        language = value.split(".")[0].replace("_", "-")
+        if language.lower().startswith("zh"):
+            return "zh-CN"
+        return DEFAULT_LOCALE
+    return DEFAULT_LOCALE
+
+
+def set_locale(locale):
+    global _locale
+    _locale = locale if locale in CATALOGS else DEFAULT_LOCALE
+
+
+def get_locale() -> str:
+    return _locale
+
+
+def tr(message, **kwargs) -> str:
+    # tr("Build {platform} failed", platform="ios")
+    translated = CATALOGS[_locale].get(message, message)
+    return translated.format(**kwargs) if kwargs else translated
@@ -0,0 +1,232 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+# zh-CN messages keyed by the english text, keep the {placeholders}
+MESSAGES = {
+    # build
+    "Building library...": "正在构建库...",
+    "Execute command: {cmd}": "执行命令: {cmd}",
+    "==== Building {platform} ====": "==== 正在构建 {platform} ====",
+    "Skip {platform}, it succeeded in the last build": "跳过 {platform}, 上次构建已成功",
+    "Build summary:": "构建结果:",
+    "ok": "成功",
+    "failed": "失败",
+    "not built": "未构建",
+    "Retry the failed platforms with `ccgo build all --resume`": "使用 `ccgo build all --resume` 重试失败的平台",
+    "Build {platform} failed with exit code {err_code}": "构建 {platform} 失败, 退出码 {err_code}",
+    "{count} tests failed: {names}": "{count} 个测试失败: {names}",
+    "{script} not found, run ccgo build in the project dir created by `ccgo lib create`":
+        "未找到 {script}, 请在 `ccgo lib create` 创建的工程目录中执行 ccgo build",
+    "Unsupported platforms: {platforms}": "不支持的平台: {platforms}",
+    "--toolchain and --toolchain-file can not be used together": "--toolchain 和 --toolchain-file 不能同时使用",
+    "Toolchain {name} not found in {path}, registered: {names}": "{path} 中未找到工具链 {name}, 已注册: {names}",
+    "Using toolchain {name} of {path}": "使用 {path} 中的工具链 {name}",
+    "Toolchain file not found: {path}": "未找到工具链文件: {path}",
+    "Xcode {version} not found, available versions:\n{available}": "未找到 Xcode {version}, 已安装的版本:\n{available}",
+    "Using Xcode {version} at {path}": "使用 Xcode {version}: {path}",
+    "--feature only works with --config-header": "--feature 需要与 --config-header 一起使用",
+    "--filter and --list only work with `ccgo build tests`": "--filter 和 --list 仅适用于 `ccgo build tests`",
+    "Distributed compiler {launcher} not found in PATH": "PATH 中未找到分布式编译器 {launcher}",
+    "Compiling through {launcher} with {jobs} jobs": "通过 {launcher} 编译, 并行任务数 {jobs}",
+    "Failed to load environment: {error}": "加载环境变量失败: {error}",
+    "Environment:": "环境变量:",
+    "Test filter: {filter}": "测试过滤: {filter}",
+    "Hardening flags of {platform}:": "{platform} 的加固编译选项:",
+    "  not applied, {reason}": "  未启用, {reason}",
+    "Generated {path}": "已生成 {path}",
+    "Removed {path} to configure again with the include dir": "已删除 {path}, 以带上头文件目录重新配置",
+    "Embedded {name} into {archive}": "已将 {name} 加入 {archive}",
+    "Build plan:": "构建计划:",
+    "  toolchain:   {toolchain}": "  工具链:   {toolchain}",
+    "  archs:       {archs}": "  架构:     {archs}",
+    "default of build_{platform}.py": "build_{platform}.py 的默认架构",
+    "  command:     {cmd}": "  命令:     {cmd}",
+    "  build dir:   {path}": "  构建目录: {path}",
+    "  generates:   {path}": "  生成:     {path}",
+    "  environment:": "  环境变量:",
+    "    (unchanged)": "    (无变化)",
+    "Nothing was built, run again without --dry-run to build": "未执行构建, 去掉 --dry-run 重新执行以构建",
+    # publish
+    "Publish plan:": "发布计划:",
+    "  destination: maven repository of the gradle project": "  目标:     gradle 工程配置的 maven 仓库",
+    "  destination: https://github.com/{repo}/releases/tag/{tag}": "  目标:     https://github.com/{repo}/releases/tag/{tag}",
+    "  release:     {flags}": "  发布类型: {flags}",
+    "  notes:       {path}": "  说明:     {path}",
+    "  assets:": "  文件:",
+    "Nothing was published, run again without --dry-run to publish": "未执行发布, 去掉 --dry-run 重新执行以发布",
+    # checks
+    "{passed} passed, {failed} failed of {total} snippets": "共 {total} 个代码片段, {passed} 个通过, {failed} 个失败",
+    "Doc coverage {coverage:.1f}% is lower than threshold {threshold}%": "文档覆盖率 {coverage:.1f}% 低于阈值 {threshold}%",
+    "Missing doc comments:": "缺少文档注释:",
+    "Found {duplicates} duplicate strong symbols in {libs} static libs": "在 {libs} 个静态库中发现 {duplicates} 个重复的强符号",
+    # errors
+    "error[{code}]: {title}, run `ccgo explain {code}` for details": "错误[{code}]: {title}, 执行 `ccgo explain {code}` 查看详情",
+    "Unknown error code {code}, see `ccgo explain` for all codes": "未知错误码 {code}, 执行 `ccgo explain` 查看所有错误码",
+    "{code}: {title} (exit code {exit_code})": "{code}: {title} (退出码 {exit_code})",
+    "Common causes:": "常见原因:",
+    "Fixes:": "解决方法:",
+    "unexpected error": "未知错误",
+    "The command failed in a way that has no better category.": "命令执行失败, 且无法归入其它类别。",
+    "a build tool or script failed before it could report why": "构建工具或脚本在报告原因之前就失败了",
+    "a bug of ccgo": "ccgo 自身的缺陷",
+    "run again with `--log-level debug` to see the commands and their output":
+        "加上 `--log-level debug` 重新执行, 查看执行的命令及其输出",
+    "report it at https://github.com/zhlinh/ccgo/issues with the log": "附上日志反馈到 https://github.com/zhlinh/ccgo/issues",
+    "invalid command line": "命令行参数无效",
+    "The arguments or options do not make sense together.": "参数或选项的组合无效。",
+    "a misspelled target or option": "目标或选项拼写错误",
+    "an option used with a target it does not apply to, like --filter without `build tests`":
+        "选项用在了不适用的目标上, 例如不带 `build tests` 使用 --filter",
+    "check `ccgo <command> --help` for the targets and options": "通过 `ccgo <command> --help` 查看目标和选项",
+    "invalid project files, options or environment": "工程文件、选项或环境无效",
+    "ccgo could not find or read a file or setting the command needs.": "ccgo 无法找到或读取命令需要的文件或配置。",
+    "running ccgo outside the project dir created by `ccgo lib create`, so build_<platform>.py is missing":
+        "未在 `ccgo lib create` 创建的工程目录中执行, 缺少 build_<platform>.py",
+    "a --toolchain-file, --env-file or compile_commands.json that does not exist":
+        "--toolchain-file、--env-file 或 compile_commands.json 不存在",
+    "a malformed .ccgo/toolchains.toml or dotenv file": ".ccgo/toolchains.toml 或 dotenv 文件格式错误",
+    "a --toolchain that is not registered as [toolchains.<name>] in .ccgo/toolchains.toml":
+        "--toolchain 未在 .ccgo/toolchains.toml 中注册为 [toolchains.<name>]",
+    "GITHUB_TOKEN or the github repo can not be found for publishing": "发布时找不到 GITHUB_TOKEN 或 github 仓库",
+    "cd into the project dir that contains the build_*.py scripts": "进入包含 build_*.py 脚本的工程目录",
+    "check the paths given on the command line": "检查命令行中的路径",
+    "build with CMAKE_EXPORT_COMPILE_COMMANDS=ON before the analyze commands":
+        "执行分析命令前, 先以 CMAKE_EXPORT_COMPILE_COMMANDS=ON 构建",
+    "required toolchain or tool not installed": "未安装所需的工具链或工具",
+    "A compiler, sdk or tool the command runs was not found.": "未找到命令需要的编译器、SDK 或工具。",
+    "the android sdk or ndk is not installed, or ANDROID_HOME is not set": "未安装 android sdk 或 ndk, 或未设置 ANDROID_HOME",
+    "the Xcode version of --xcode is not in /Applications": "/Applications 中没有 --xcode 指定的 Xcode 版本",
+    "icecc, distcc, perf, sdkmanager or minidump-stackwalk is not in PATH":
+        "PATH 中没有 icecc、distcc、perf、sdkmanager 或 minidump-stackwalk",
+    "see `ccgo toolchain list` for the toolchains found on this host": "通过 `ccgo toolchain list` 查看本机的工具链",
+    "install the ndk with `ccgo toolchain install ndk <version>`": "通过 `ccgo toolchain install ndk <version>` 安装 ndk",
+    "pin an installed ndk or xcode with `ccgo toolchain use`": "通过 `ccgo toolchain use` 固定已安装的 ndk 或 xcode",
+    "build or compile failed": "构建或编译失败",
+    "The build script of a platform exited with an error.": "平台的构建脚本执行出错。",
+    "a compile or link error of the project sources": "工程源码编译或链接错误",
+    "the cmake configure failed, often for a wrong toolchain": "cmake 配置失败, 通常是工具链不正确",
+    "read the compiler output above, or write it to a file with `--log-file`": "查看上方的编译器输出, 或通过 `--log-file` 写入文件",
+    "retry only the failed platforms with `ccgo build all --resume`": "通过 `ccgo build all --resume` 只重试失败的平台",
+    "tests failed": "测试失败",
+    "The tests were built but some of them failed.": "测试已构建, 但部分测试失败。",
+    "a regression of the code under test": "被测代码出现回归",
+    "rerun the failed tests only with `ccgo build tests --filter <name>`": "通过 `ccgo build tests --filter <name>` 只重跑失败的测试",
+    "packaging failed": "打包失败",
+    "The sdk archives could not be read, merged or written.": "无法读取、合并或写入 sdk 压缩包。",
+    "archives of different versions or revisions given to `ccgo package merge`":
+        "传给 `ccgo package merge` 的压缩包版本或修订不同",
+    "the same file with different contents in the merged archives": "合并的压缩包中同一文件内容不同",
+    "a corrupted zip": "zip 文件已损坏",
+    "merge the archives of the same revision, or pass --allow-inconsistent": "合并同一修订的压缩包, 或加上 --allow-inconsistent",
+    "compare the archives with `ccgo diff`": "通过 `ccgo diff` 比较压缩包",
+    "network error": "网络错误",
+    "A remote server could not be reached or refused the request.": "无法连接远程服务器, 或请求被拒绝。",
+    "no network, or a proxy is needed": "没有网络, 或需要代理",
+    "a corporate CA certificate is not trusted": "企业 CA 证书未被信任",
+    "an expired or missing token": "token 已过期或缺失",
+    "set CCGO_PROXY and CCGO_CA_BUNDLE, see the Network section of the README":
+        "设置 CCGO_PROXY 和 CCGO_CA_BUNDLE, 参见 README 的 Network 一节",
+    "raise CCGO_NET_RETRIES or CCGO_NET_TIMEOUT for flaky networks": "网络不稳定时调大 CCGO_NET_RETRIES 或 CCGO_NET_TIMEOUT",
+    "check found issues": "检查发现问题",
+    "A check command ran successfully and found issues in the project.": "检查命令执行成功, 并在工程中发现了问题。",
+    "doc coverage below the threshold or failing doc snippets": "文档覆盖率低于阈值, 或文档代码片段编译失败",
+    "duplicate symbols, missing hardening or third-party licenses": "存在重复符号, 或缺少加固选项或第三方许可证",
+    "an archive which does not match its checksum or provenance": "压缩包与其校验和或来源证明不一致",
+    "fix the issues listed above, they are the report of the check": "修复上方列出的问题, 它们就是检查报告",
+    "publishing failed": "发布失败",
+    "The library could not be published to the repository.": "无法将库发布到仓库。",
+    "missing or wrong credentials of the maven repository": "maven 仓库的凭据缺失或错误",
+    "the version is published already": "该版本已发布",
+    "check the gradle output above": "查看上方的 gradle 输出",
+    "bump the version with `ccgo tag`": "通过 `ccgo tag` 升级版本",
+    "android ndk or sdk not found": "未找到 android ndk 或 sdk",
+    "The ndk of the requested version, the android sdk or its sdkmanager is not installed.":
+        "未安装所需版本的 ndk、android sdk 或其 sdkmanager。",
+    "ANDROID_HOME is not set and the sdk is not in the default dir of android studio":
+        "未设置 ANDROID_HOME, 且 sdk 不在 android studio 的默认目录",
+    "the pinned ndk was removed from the sdk": "固定的 ndk 已从 sdk 中移除",
+    "see `ccgo toolchain list ndk` for the installed ndks": "通过 `ccgo toolchain list ndk` 查看已安装的 ndk",
+    "xcode version not found": "未找到 xcode 版本",
+    "No installed Xcode.app in /Applications matches the version of --xcode or the [xcode] pin.":
+        "/Applications 中没有与 --xcode 或 [xcode] 固定版本匹配的 Xcode.app。",
+    "the pinned xcode was removed or renamed": "固定的 xcode 已被移除或重命名",
+    "see `ccgo toolchain list xcode` for the installed versions": "通过 `ccgo toolchain list xcode` 查看已安装的版本",
+    "pin an installed one with `ccgo toolchain use xcode <version>`":
+        "通过 `ccgo toolchain use xcode <version>` 固定已安装的版本",
+    "tool can not read the binaries of the target": "工具无法读取目标平台的二进制",
+    "nm, readelf, objdump or otool of the host failed to read a binary built for another target.":
+        "本机的 nm、readelf、objdump 或 otool 无法读取为其他目标平台构建的二进制。",
+    "the libs are built by the ndk or a cross toolchain the host tools do not understand": "库由本机工具无法识别的 ndk 或交叉工具链构建",
+    "pass the tools of the target toolchain, like --nm llvm-nm or --readelf llvm-readelf":
+        "传入目标工具链的工具, 例如 --nm llvm-nm 或 --readelf llvm-readelf",
+    "tool not found in PATH": "PATH 中未找到工具",
+    "A tool the command runs, like a profiler, analyzer or dump_syms, is not installed.":
+        "未安装命令需要的工具, 例如性能分析器、静态分析器或 dump_syms。",
+    "the tool is not installed or not in PATH": "工具未安装或不在 PATH 中",
+    "install the tool named above, or pass its path with the option of the command": "安装上方提示的工具, 或通过命令的选项传入其路径",
+    "archive can not be read": "无法读取压缩包",
+    "An sdk archive is missing, truncated or not a zip.": "sdk 压缩包不存在、不完整或不是 zip。",
+    "the build did not finish, or the download was cut off": "构建未完成, 或下载被中断",
+    "build or download the archive again": "重新构建或下载压缩包",
+    "files conflict between archives": "压缩包之间文件冲突",
+    "The archives to merge have the same file with different contents.": "要合并的压缩包中同一文件内容不同。",
+    "a header or lib built differently for two platforms at the same path": "两个平台在同一路径下构建出不同的头文件或库",
+    "build all the archives from the same revision and flags": "以同一修订和编译选项构建所有压缩包",
+    "version conflict between archives": "压缩包之间版本冲突",
+    "The build_info.json of the archives to merge differ in name, version or revision.":
+        "要合并的压缩包的 build_info.json 中名称、版本或修订不同。",
+    "archives of an older build left in bin": "bin 中残留了旧构建的压缩包",
+    "remove the old archives and build all platforms from the same revision": "删除旧的压缩包, 以同一修订构建所有平台",
+    "build script not found": "未找到构建脚本",
+    "build_<platform>.py of the platform is not in the current dir.": "当前目录中没有该平台的 build_<platform>.py。",
+    "running ccgo outside the project dir created by `ccgo lib create`": "未在 `ccgo lib create` 创建的工程目录中执行",
+    "custom toolchain not registered": "自定义工具链未注册",
+    "The --toolchain is not registered as [toolchains.<name>] in .ccgo/toolchains.toml.":
+        "--toolchain 未在 .ccgo/toolchains.toml 中注册为 [toolchains.<name>]。",
+    "a misspelled toolchain name": "工具链名称拼写错误",
+    "see `ccgo toolchain list` for the registered toolchains": "通过 `ccgo toolchain list` 查看已注册的工具链",
+    "platform build failed": "平台构建失败",
+    "The build script of a platform failed to configure or compile.": "平台的构建脚本配置或编译失败。",
+    "a compile error of the code": "代码编译错误",
+    "read the compiler output above, the first error is usually the cause": "查看上方的编译器输出, 第一个错误通常就是原因",
+    "retry the failed platforms with `ccgo build all --resume`": "通过 `ccgo build all --resume` 重试失败的平台",
+    "test cases failed": "测试用例失败",
+    "The tests were built and ran, and some of them failed.": "测试已构建并运行, 但部分测试失败。",
+    "a regression of the code or a flaky test": "代码出现回归, 或测试不稳定",
+    "rerun the failed tests with `ccgo build tests --filter <name>`": "通过 `ccgo build tests --filter <name>` 重跑失败的测试",
+    "download failed": "下载失败",
+    "A release or archive could not be downloaded completely.": "无法完整下载发布或压缩包。",
+    "a proxy or firewall blocks the connection": "代理或防火墙阻断了连接",
+    "the rate limit of the github api without a token": "未使用 token 时 github api 的频率限制",
+    "set GITHUB_TOKEN to raise the rate limit": "设置 GITHUB_TOKEN 以提高频率限制",
+    "github token not set": "未设置 github token",
+    "Publishing a github release needs a token with access to the repository.": "发布 github release 需要有仓库权限的 token。",
+    "GITHUB_TOKEN or GH_TOKEN is not set": "未设置 GITHUB_TOKEN 或 GH_TOKEN",
+    "set GITHUB_TOKEN, in github actions from secrets.GITHUB_TOKEN":
+        "设置 GITHUB_TOKEN, github actions 中取自 secrets.GITHUB_TOKEN",
+    "duplicate symbols": "重复符号",
+    "The same symbol is defined strongly in objects of more than one static lib.": "同一符号在多个静态库的目标文件中被强定义。",
+    "a third-party lib vendored by two modules": "两个模块都内置了同一个第三方库",
+    "link the shared code from one module only": "只从一个模块链接共享代码",
+    "missing hardening": "缺少加固",
+    "A required security mitigation is not applied to the binary.": "二进制未启用必需的安全缓解措施。",
+    "the binary was built without `ccgo build --hardening`": "二进制构建时未使用 `ccgo build --hardening`",
+    "build again with --hardening": "使用 --hardening 重新构建",
+    "third-party license missing or unknown": "第三方许可证缺失或未知",
+    "A dependency has no license file, or its license is none of the known ones.": "依赖没有许可证文件, 或其许可证不是已知的许可证。",
+    "a vendored dependency copied without its license file": "复制依赖时遗漏了许可证文件",
+    "add the license file to the dir of the dependency, and review unknown licenses by hand":
+        "将许可证文件放入依赖目录, 并人工审查未知许可证",
+    "archive does not match its checksum or provenance": "压缩包与其校验和或来源证明不一致",
+    "The sha256 of an archive differs from the published checksum or its provenance.": "压缩包的 sha256 与发布的校验和或来源证明不同。",
+    "the archive was modified or replaced after it was built": "压缩包在构建后被修改或替换",
+    "download the archive again from a trusted source": "从可信来源重新下载压缩包",
+}
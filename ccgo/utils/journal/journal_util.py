@@ -0,0 +1,74 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import glob
+import json
+import time
+
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+JOURNAL_DIR = ".ccgo"
+JOURNAL_FILE = "journal.jsonl"
+# set CCGO_JOURNAL=0 to not record the invocations
+JOURNAL_ENV = "CCGO_JOURNAL"
+
+
+def get_journal_path(project_dir=".") -> str:
+    return os.path.join(project_dir, JOURNAL_DIR, JOURNAL_FILE)
+
+
+def is_journal_enabled(project_dir=".") -> bool:
+    # only in project dirs, never leave .ccgo in other dirs
+    if os.environ.get(JOURNAL_ENV) == "0":
+        return False
+    return os.path.isdir(os.path.join(project_dir, JOURNAL_DIR)) or \
+        len(glob.glob(os.path.join(project_dir, "build_*.py"))) > 0
+
+
+def record_invocation(command, target, duration_second, exit_code, error=None, project_dir="."):
+    # the journal stays local, nothing is sent anywhere
+    if not is_journal_enabled(project_dir):
+        return
+    record = {
+        "time": int(time.time()),
+        "command": command,
+        "target": target,
+        "duration_ms": int(duration_second * 1000),
+        "success": exit_code == 0,
+        "exit_code": exit_code,
+        "error": error,
+    }
+    journal_path = get_journal_path(project_dir)
+    try:
+        os.makedirs(os.path.dirname(journal_path), exist_ok=True)
+        with open(journal_path, "a") as f:
+            f.write(json.dumps(record) + "\n")
+    except OSError as e:
+        # the journal must never break the command
+        logger.debug(f"Failed to write {journal_path}: {e}")
+
+
+def load_journal(project_dir=".") -> list:
+    journal_path = get_journal_path(project_dir)
+    if not os.path.exists(journal_path):
+        return []
+    records = []
+    with open(journal_path, "r") as f:
+        for line in f:
+            try:
+                records.append(json.loads(line))
+            except ValueError:
+                # skip the line broken by an interrupted write
+                continue
+    return records
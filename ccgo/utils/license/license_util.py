@@ -0,0 +1,66 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+
+DEFAULT_THIRD_PARTY_DIR = "third_party"
+NOTICES_NAME = "THIRD-PARTY-NOTICES.txt"
+LICENSE_FILE_PATTERN = re.compile(r"^(LICEN[CS]E|COPYING|NOTICE|COPYRIGHT)([-._].*)?$", re.IGNORECASE)
+# first match wins, so the more specific texts go first
+LICENSE_KEYWORDS = [
+    ("Apache-2.0", "Apache License"),
+    ("MPL-2.0", "Mozilla Public License"),
+    ("LGPL", "GNU LESSER GENERAL PUBLIC LICENSE"),
+    ("GPL", "GNU GENERAL PUBLIC LICENSE"),
+    ("BSL-1.0", "Boost Software License"),
+    ("BSD-3-Clause", "Neither the name"),
+    ("BSD-2-Clause", "Redistributions in binary form"),
+    ("Zlib", "This software is provided 'as-is'"),
+    ("MIT", "Permission is hereby granted, free of charge"),
+]
+
+
+def detect_license_name(text) -> str:
+    return next((name for name, keyword in LICENSE_KEYWORDS if keyword.lower() in text.lower()), "unknown")
+
+
+def collect_licenses(third_party_dir=DEFAULT_THIRD_PARTY_DIR) -> list:
+    # return [{"name", "license", "files": [(path, text)]}] of each dep dir,
+    # files is empty if the dep has no license file
+    deps = []
+    if not os.path.isdir(third_party_dir):
+        return deps
+    for name in sorted(os.listdir(third_party_dir)):
+        dep_dir = os.path.join(third_party_dir, name)
+        if not os.path.isdir(dep_dir) or name.startswith("."):
+            continue
+        files = []
+        for file_name in sorted(os.listdir(dep_dir)):
+            path = os.path.join(dep_dir, file_name)
+            if LICENSE_FILE_PATTERN.match(file_name) and os.path.isfile(path):
+                with open(path, "r", errors="replace") as f:
+                    files.append((path, f.read()))
+        license_name = detect_license_name("\n".join([x[1] for x in files])) if files else None
+        deps.append({"name": name, "license": license_name, "files": files})
+    return deps
+
+
+def render_notices(deps, template=None) -> str:
+    # template is a text with {notices} replaced by the license texts
+    sections = []
+    for dep in deps:
+        texts = "\n\n".join([text.strip() for path, text in dep["files"]])
+        sections.append(f"{'=' * 72}\n{dep['name']} ({dep['license']})\n{'=' * 72}\n\n{texts}\n")
+    notices = "\n".join(sections)
+    if template:
+        return template.replace("{notices}", notices)
+    return f"This software contains the following third-party components.\n\n{notices}"
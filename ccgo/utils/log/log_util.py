@@ -0,0 +1,99 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import json
+import logging
+
+LOGGER_NAME = "ccgo"
+# output of the build tools, only written to the log file
+OUTPUT_LOGGER_NAME = "ccgo.output"
+LOG_LEVELS = ["debug", "info", "warning", "error"]
+LOG_FORMATS = ["text", "json"]
+# set CCGO_LOG_LEVEL=debug to change the default level
+LOG_LEVEL_ENV = "CCGO_LOG_LEVEL"
+FILE_LOG_FORMAT = "%(asctime)s %(levelname)-7s %(context_text)s%(message)s"
+
+# fields like platform and arch attached to every record
+_log_context = {}
+
+
+class ContextFilter(logging.Filter):
+    def filter(self, record):
+        record.context = dict(_log_context)
+        record.context_text = "".join([f"[{v}] " for v in _log_context.values()])
+        return True
+
+
+class JsonFormatter(logging.Formatter):
+    def format(self, record):
+        item = {
+            "time": self.formatTime(record, "%Y-%m-%dT%H:%M:%S"),
+            "level": record.levelname.lower(),
+            "logger": record.name,
+            "message": record.getMessage(),
+        }
+        item.update(getattr(record, "context", {}))
+        if record.exc_info:
+            item["exception"] = self.formatException(record.exc_info)
+        return json.dumps(item, ensure_ascii=False)
+
+
+def get_default_log_level() -> str:
+    level = os.environ.get(LOG_LEVEL_ENV, "info").lower()
+    return level if level in LOG_LEVELS else "info"
+
+
+def setup_logging(level=None, log_file=None, log_format="text"):
+    level = getattr(logging, (level or get_default_log_level()).upper())
+    context_filter = ContextFilter()
+    logger = logging.getLogger(LOGGER_NAME)
+    logger.handlers = []
+    logger.setLevel(logging.DEBUG)
+    console = logging.StreamHandler(sys.stderr)
+    console.setLevel(level)
+    console.addFilter(context_filter)
+    console.setFormatter(JsonFormatter() if log_format == "json" else logging.Formatter("%(message)s"))
+    logger.addHandler(console)
+    output_logger = logging.getLogger(OUTPUT_LOGGER_NAME)
+    output_logger.handlers = []
+    output_logger.propagate = False
+    output_logger.setLevel(logging.DEBUG)
+    if log_file:
+        os.makedirs(os.path.dirname(os.path.abspath(log_file)), exist_ok=True)
+        file_handler = logging.FileHandler(log_file, encoding="utf-8")
+        file_handler.setLevel(logging.DEBUG)
+        file_handler.addFilter(context_filter)
+        file_handler.setFormatter(JsonFormatter() if log_format == "json" else logging.Formatter(FILE_LOG_FORMAT))
+        logger.addHandler(file_handler)
+        output_logger.addHandler(file_handler)
+
+
+def get_logger(name=None):
+    return logging.getLogger(f"{LOGGER_NAME}.{name}" if name else LOGGER_NAME)
+
+
+def get_output_logger():
+    return logging.getLogger(OUTPUT_LOGGER_NAME)
+
+
+def has_log_file() -> bool:
+    return any([isinstance(x, logging.FileHandler) for x in get_output_logger().handlers])
+
+
+def set_log_context(**fields):
+    # like set_log_context(platform="android"), None removes the field
+    for key, value in fields.items():
+        if value is None:
+            _log_context.pop(key, None)
+        else:
+            _log_context[key] = value
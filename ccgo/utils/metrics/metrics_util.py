@@ -0,0 +1,90 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import json
+import time
+import socket
+
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+METRICS_DIR = ".ccgo"
+METRICS_FILE = "build_metrics.jsonl"
+# set CCGO_METRICS=1 to enable metrics without --metrics
+METRICS_ENV = "CCGO_METRICS"
+# set CCGO_STATSD=host:port to also export metrics to statsd
+STATSD_ENV = "CCGO_STATSD"
+
+
+def is_metrics_enabled(flag=False) -> bool:
+    return flag or os.environ.get(METRICS_ENV) == "1"
+
+
+def get_metrics_path(project_dir=".") -> str:
+    return os.path.join(project_dir, METRICS_DIR, METRICS_FILE)
+
+
+def record_build_metrics(platform, arch, duration_second, err_code, project_dir="."):
+    record = {
+        "time": int(time.time()),
+        "platform": platform,
+        "arch": arch,
+        "duration_ms": int(duration_second * 1000),
+        "success": err_code == 0,
+        "exit_code": err_code,
+    }
+    metrics_path = get_metrics_path(project_dir)
+    try:
+        os.makedirs(os.path.dirname(metrics_path), exist_ok=True)
+        with open(metrics_path, "a") as f:
+            f.write(json.dumps(record) + "\n")
+    except OSError as e:
+        # metrics must never break the build
+        logger.warning(f"Failed to write {metrics_path}: {e}")
+    statsd_address = os.environ.get(STATSD_ENV)
+    if statsd_address:
+        send_statsd(statsd_address, record)
+    return record
+
+
+def load_build_metrics(project_dir=".") -> list:
+    metrics_path = get_metrics_path(project_dir)
+    if not os.path.exists(metrics_path):
+        return []
+    records = []
+    with open(metrics_path, "r") as f:
+        for line in f:
+            try:
+                records.append(json.loads(line))
+            except ValueError:
+                # skip the line broken by an interrupted write
+                continue
+    return records
+
+
+def send_statsd(address, record):
+    host, _, port = address.rpartition(":")
+    status = "success" if record["success"] else "failure"
+    tags = f"#platform:{record['platform']}"
+    lines = [
+        f"ccgo.build.duration:{record['duration_ms']}|ms|{tags}",
+        f"ccgo.build.{status}:1|c|{tags}",
+    ]
+    sock = socket.socket(socket.AF_INET, socket.SOCK_DGRAM)
+    try:
+        sock.sendto("\n".join(lines).encode("utf-8"), (host or "127.0.0.1", int(port)))
+    except (OSError, ValueError) as e:
+        # metrics must never break the build
+        logger.warning(f"Failed to send metrics to statsd {address}: {e}")
+    finally:
+        sock.close()
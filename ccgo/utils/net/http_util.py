@@ -0,0 +1,109 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import ssl
+import json
+import time
+import urllib.error
+import urllib.request
+
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+DEFAULT_TIMEOUT_SECOND = 60
+DEFAULT_RETRIES = 3
+# only requests which can be sent twice safely are retried
+RETRY_METHODS = ["GET", "HEAD", "PUT", "DELETE"]
+# 0 is the server can not be reached
+RETRY_STATUSES = [0, 429, 500, 502, 503, 504]
+
+
+# names of the invalid variables already warned about
+_warned_names = set()
+
+
+def get_env_int(name, default, minimum=0) -> int:
+    # a typo like CCGO_NET_TIMEOUT=60s falls back to the default
+    value = os.environ.get(name)
+    if value is None:
+        return default
+    try:
+        number = int(value)
+        if number >= minimum:
+            return number
+    except ValueError:
+        pass
+    if name not in _warned_names:
+        _warned_names.add(name)
+        logger.warning(f"Ignore {name}={value}, it is not an integer >= {minimum}, use {default}")
+    return default
+
+
+def get_net_config() -> dict:
+    # network config of all commands, read from environment
+    return {
+        "proxy": os.environ.get("CCGO_PROXY"),
+        "no_proxy": os.environ.get("CCGO_NO_PROXY"),
+        "ca_bundle": os.environ.get("CCGO_CA_BUNDLE"),
+        "retries": get_env_int("CCGO_NET_RETRIES", DEFAULT_RETRIES),
+        "timeout": get_env_int("CCGO_NET_TIMEOUT", DEFAULT_TIMEOUT_SECOND, minimum=1),
+    }
+
+
+def apply_net_env():
+    # export the config in the variables of git, curl and python,
+    # so the build scripts and tools run by ccgo follow it too
+    config = get_net_config()
+    if config["proxy"]:
+        for name in ["HTTP_PROXY", "HTTPS_PROXY", "http_proxy", "https_proxy"]:
+            os.environ[name] = config["proxy"]
+    if config["no_proxy"]:
+        os.environ["NO_PROXY"] = os.environ["no_proxy"] = config["no_proxy"]
+    if config["ca_bundle"]:
+        for name in ["SSL_CERT_FILE", "GIT_SSL_CAINFO", "CURL_CA_BUNDLE", "REQUESTS_CA_BUNDLE"]:
+            os.environ[name] = config["ca_bundle"]
+
+
+def http_request(method, url, headers=None, data=None, timeout_second=None):
+    # return (status, body), status is 0 if the server can not be reached
+    config = get_net_config()
+    timeout_second = timeout_second or config["timeout"]
+    context = ssl.create_default_context(cafile=config["ca_bundle"]) if config["ca_bundle"] else None
+    retries = config["retries"] if method in RETRY_METHODS else 0
+    for attempt in range(retries + 1):
+        if attempt > 0:
+            time.sleep(2 ** (attempt - 1))
+        request = urllib.request.Request(url, data=data, headers=headers or {}, method=method)
+        try:
+            with urllib.request.urlopen(request, timeout=timeout_second, context=context) as response:
+                status, body = response.status, response.read()
+        except urllib.error.HTTPError as e:
+            status, body = e.code, e.read()
+        except (urllib.error.URLError, OSError) as e:
+            status, body = 0, str(getattr(e, "reason", e)).encode("utf-8")
+        if status not in RETRY_STATUSES:
+            break
+    return status, body
+
+
+def http_request_json(method, url, headers=None, payload=None, timeout_second=None):
+    headers = dict(headers or {})
+    data = None
+    if payload is not None:
+        data = json.dumps(payload).encode("utf-8")
+        headers["Content-Type"] = "application/json"
+    status, body = http_request(method, url, headers, data, timeout_second)
+    try:
+        return status, json.loads(body.decode("utf-8")) if body else None
+    except ValueError:
+        return status, body.decode("utf-8", "replace")
@@ -0,0 +1,140 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import json
+import zipfile
+import hashlib
+import tempfile
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.cmd.cmd_util import exec_command
+from utils.package.package_util import find_build_info_name
+
+LIB_EXTENSIONS = [".a", ".lib", ".so", ".dylib", ".dll", ".har", ".aar"]
+SHARED_LIB_EXTENSIONS = [".so", ".dylib"]
+
+
+def load_snapshot(path) -> dict:
+    # return {"files": {name: (size, sha256)}, "build_info": dict or None}
+    # of an sdk zip, or of a single build_info.json
+    if not zipfile.is_zipfile(path):
+        with open(path, "r") as f:
+            return {"files": {}, "build_info": json.load(f)}
+    files = {}
+    build_info = None
+    with zipfile.ZipFile(path) as archive:
+        names = [x for x in archive.namelist() if not x.endswith("/")]
+        build_info_name = find_build_info_name(names)
+        for name in names:
+            data = archive.read(name)
+            if name == build_info_name:
+                build_info = json.loads(data.decode("utf-8"))
+            files[name] = (len(data), hashlib.sha256(data).hexdigest())
+    return {"files": files, "build_info": build_info}
+
+
+def is_lib(name) -> bool:
+    # .so.1 versioned names are libs too
+    base = os.path.basename(name)
+    return any([base.endswith(x) or f"{x}." in base for x in LIB_EXTENSIONS])
+
+
+def is_shared_lib(name) -> bool:
+    base = os.path.basename(name)
+    return any([base.endswith(x) or f"{x}." in base for x in SHARED_LIB_EXTENSIONS])
+
+
+def diff_files(old_files, new_files) -> dict:
+    added = sorted(set(new_files) - set(old_files))
+    removed = sorted(set(old_files) - set(new_files))
+    changed = sorted([x for x in set(old_files) & set(new_files) if old_files[x][1] != new_files[x][1]])
+    return {
+        "added": [{"name": x, "size": new_files[x][0]} for x in added],
+        "removed": [{"name": x, "size": old_files[x][0]} for x in removed],
+        "changed": [{"name": x, "old_size": old_files[x][0], "new_size": new_files[x][0]} for x in changed],
+    }
+
+
+def diff_lib_sizes(old_files, new_files) -> list:
+    libs = sorted([x for x in set(old_files) | set(new_files) if is_lib(x)])
+    result = []
+    for name in libs:
+        old_size = old_files[name][0] if name in old_files else 0
+        new_size = new_files[name][0] if name in new_files else 0
+        if old_size != new_size:
+            result.append({"name": name, "old_size": old_size, "new_size": new_size, "delta": new_size - old_size})
+    return result
+
+
+def diff_build_info(old_info, new_info) -> list:
+    old_info = old_info or {}
+    new_info = new_info or {}
+    result = []
+    for key in sorted(set(old_info) | set(new_info)):
+        if old_info.get(key) != new_info.get(key):
+            result.append({"key": key, "old": old_info.get(key), "new": new_info.get(key)})
+    return result
+
+
+def list_exported_symbols(path, nm="nm") -> set:
+    # dynamic symbols of elf, global defined symbols of mach-o
+    flags = "-D -g -P" if ".so" in os.path.basename(path) else "-g -P"
+    err_code, output = exec_command(f"{nm} {flags} \"{path}\"")
+    if err_code != 0:
+        # an empty set would report every symbol as added or removed
+        raise RuntimeError(f"{nm} failed to read {os.path.basename(path)} with exit code {err_code}")
+    symbols = set()
+    for line in output.splitlines():
+        fields = line.split()
+        if len(fields) >= 2 and fields[1] not in ["U", "w", "v"]:
+            symbols.add(fields[0])
+    return symbols
+
+
+def diff_symbols(old_path, new_path, names, nm="nm") -> list:
+    # return [{"name", "added", "removed"}] of the shared libs in both zips
+    result = []
+    with tempfile.TemporaryDirectory() as work_dir, \
+            zipfile.ZipFile(old_path) as old_archive, zipfile.ZipFile(new_path) as new_archive:
+        for name in names:
+            old_lib = old_archive.extract(name, os.path.join(work_dir, "old"))
+            new_lib = new_archive.extract(name, os.path.join(work_dir, "new"))
+            old_symbols = list_exported_symbols(old_lib, nm)
+            new_symbols = list_exported_symbols(new_lib, nm)
+            added = sorted(new_symbols - old_symbols)
+            removed = sorted(old_symbols - new_symbols)
+            if added or removed:
+                result.append({"name": name, "added": added, "removed": removed})
+    return result
+
+
+def diff_snapshots(old_path, new_path, nm="nm") -> dict:
+    old = load_snapshot(old_path)
+    new = load_snapshot(new_path)
+    files = diff_files(old["files"], new["files"])
+    changed_shared_libs = [x["name"] for x in files["changed"] if is_shared_lib(x["name"])]
+    symbols = []
+    if changed_shared_libs and zipfile.is_zipfile(old_path) and zipfile.is_zipfile(new_path):
+        symbols = diff_symbols(old_path, new_path, changed_shared_libs, nm)
+    return {
+        "build_info": diff_build_info(old["build_info"], new["build_info"]),
+        "files": files,
+        "libs": diff_lib_sizes(old["files"], new["files"]),
+        "symbols": symbols,
+    }
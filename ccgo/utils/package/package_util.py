@@ -0,0 +1,120 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import json
+import zipfile
+import hashlib
+
+BUILD_INFO_NAME = "build_info.json"
+# fields must be the same in the build_info.json of all merged archives
+BUILD_INFO_CONSISTENT_KEYS = ["name", "version", "revision"]
+
+
+def is_sdk_archive_of(name, platform) -> bool:
+    # foo_IOS_SDK-1.2.0.zip is the sdk of ios, but neither foo_IOS_SYMBOLS-1.2.0.zip
+    # nor foo_MACOS_SDK-1.2.0.zip
+    if not name.lower().endswith(".zip"):
+        return False
+    segments = [x.lower() for x in re.split(r"[_\-.]", name[:-len(".zip")])]
+    return platform.lower() in segments and "symbols" not in segments
+
+
+def find_build_info_name(names):
+    # build_info.json at the root or under the single top-level dir
+    candidates = [x for x in names if os.path.basename(x) == BUILD_INFO_NAME]
+    return min(candidates, key=lambda x: x.count("/")) if candidates else None
+
+
+def load_archive(path) -> dict:
+    # return {"path", "files": {name: sha256}, "build_info": dict or None}
+    files = {}
+    build_info = None
+    with zipfile.ZipFile(path) as archive:
+        names = [x for x in archive.namelist() if not x.endswith("/")]
+        build_info_name = find_build_info_name(names)
+        for name in names:
+            data = archive.read(name)
+            if name == build_info_name:
+                build_info = json.loads(data.decode("utf-8"))
+                continue
+            files[name] = hashlib.sha256(data).hexdigest()
+    return {"path": path, "files": files, "build_info": build_info, "build_info_name": build_info_name}
+
+
+def check_consistency(archives) -> list:
+    # return error messages of the build_info.json fields which differ
+    errors = []
+    infos = [(x["path"], x["build_info"]) for x in archives if x["build_info"] is not None]
+    for key in BUILD_INFO_CONSISTENT_KEYS:
+        values = {}
+        for path, info in infos:
+            if key in info:
+                values.setdefault(str(info[key]), []).append(path)
+        if len(values) > 1:
+            detail = ", ".join([f"{value} in {paths}" for value, paths in values.items()])
+            errors.append(f"{BUILD_INFO_NAME} {key} differs: {detail}")
+    return errors
+
+
+def find_conflicts(archives) -> dict:
+    # return {name: [path]} of files which have different content in archives
+    owners = {}
+    for archive in archives:
+        for name, digest in archive["files"].items():
+            owners.setdefault(name, {}).setdefault(digest, []).append(archive["path"])
+    return {name: sum(digests.values(), []) for name, digests in owners.items() if len(digests) > 1}
+
+
+def merge_build_info(archives) -> dict:
+    infos = [x for x in archives if x["build_info"] is not None]
+    merged = {}
+    for key in BUILD_INFO_CONSISTENT_KEYS:
+        for archive in infos:
+            if key in archive["build_info"]:
+                merged[key] = archive["build_info"][key]
+                break
+    merged["archives"] = [{"archive": os.path.basename(x["path"]), **x["build_info"]} for x in infos]
+    return merged
+
+
+def merge_archives(archives, output):
+    # archives must be checked by find_conflicts before
+    build_info_name = next((x["build_info_name"] for x in archives if x["build_info_name"]), BUILD_INFO_NAME)
+    written = set()
+    with zipfile.ZipFile(output, "w", zipfile.ZIP_DEFLATED) as merged:
+        for archive in archives:
+            with zipfile.ZipFile(archive["path"]) as source:
+                for info in source.infolist():
+                    if info.is_dir() or info.filename in written or info.filename == archive["build_info_name"]:
+                        continue
+                    merged.writestr(info, source.read(info.filename))
+                    written.add(info.filename)
+        merged.writestr(build_info_name, json.dumps(merge_build_info(archives), indent=2) + "\n")
+    return len(written)
+
+
+def add_file_to_archive(path, name, content):
+    # replace the entry if the archive has it already
+    with zipfile.ZipFile(path) as archive:
+        names = archive.namelist()
+    if name not in names:
+        with zipfile.ZipFile(path, "a", zipfile.ZIP_DEFLATED) as archive:
+            archive.writestr(name, content)
+        return
+    temp_path = f"{path}.tmp"
+    with zipfile.ZipFile(path) as source, zipfile.ZipFile(temp_path, "w", zipfile.ZIP_DEFLATED) as target:
+        for info in source.infolist():
+            if info.filename != name:
+                target.writestr(info, source.read(info.filename))
+        target.writestr(name, content)
+    os.replace(temp_path, path)
@@ -0,0 +1,65 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import sys
+import shutil
+
+PROFILE_DIR = os.path.join(".ccgo", "profile")
+# keep frame pointers so the stacks can be walked without dwarf unwinding
+FRAME_POINTER_FLAGS = "/Zi /Oy-" if sys.platform == "win32" else "-g -fno-omit-frame-pointer"
+FLAMEGRAPH_RENDERERS = ["inferno-flamegraph", "flamegraph.pl"]
+# "  7f1c2a3b4c5d func+0x1d (/usr/lib/libc.so.6)" of perf script
+PERF_FRAME_PATTERN = re.compile(r"^\s*[0-9a-fA-F]+\s+(?P<symbol>.+?)(?:\s+\((?P<module>[^)]*)\))?$")
+
+
+def get_profiler_name():
+    if sys.platform == "darwin":
+        return "xctrace"
+    if sys.platform == "win32":
+        return "wpr"
+    return "perf"
+
+
+def collapse_perf_script(text) -> dict:
+    # return {"comm;outer;...;inner": samples} of perf script output
+    folded = {}
+    comm = None
+    frames = []
+    for line in text.splitlines() + [""]:
+        if not line.strip():
+            if comm is not None and frames:
+                stack = ";".join([comm] + list(reversed(frames)))
+                folded[stack] = folded.get(stack, 0) + 1
+            comm = None
+            frames = []
+            continue
+        if comm is None and not line.startswith((" ", "\t")):
+            comm = line.split()[0]
+            continue
+        match = PERF_FRAME_PATTERN.match(line)
+        if comm is not None and match:
+            symbol = re.sub(r"\+0x[0-9a-fA-F]+$", "", match.group("symbol"))
+            if symbol == "[unknown]" and match.group("module"):
+                symbol = f"[{os.path.basename(match.group('module'))}]"
+            frames.append(symbol.replace(";", ":"))
+    return folded
+
+
+def write_folded(path, folded):
+    with open(path, "w") as f:
+        for stack, count in sorted(folded.items()):
+            f.write(f"{stack} {count}\n")
+
+
+def find_flamegraph_renderer():
+    return next((x for x in FLAMEGRAPH_RENDERERS if shutil.which(x)), None)
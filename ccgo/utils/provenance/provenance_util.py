@@ -0,0 +1,151 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import socket
+import hashlib
+import datetime
+import urllib.parse
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.cmd.cmd_util import exec_command
+from utils.git.git_util import get_head_revision
+from utils.git.git_util import get_remote_url
+from utils.git.git_util import is_work_tree_clean
+from utils.toolchain.ndk_util import get_ndk_version
+from utils.toolchain.xcode_util import get_xcode_version
+from utils.toolchain.toolchain_config_util import CUSTOM_TOOLCHAIN_PREFIX
+from utils.toolchain.toolchain_config_util import load_toolchain_config
+from utils.toolchain.toolchain_config_util import get_custom_toolchains
+
+STATEMENT_TYPE = "https://in-toto.io/Statement/v1"
+PREDICATE_TYPE = "https://slsa.dev/provenance/v1"
+BUILD_TYPE = "https://github.com/zhlinh/ccgo/build/v1"
+PROVENANCE_SUFFIX = ".provenance.json"
+SIGNATURE_SUFFIX = ".sig"
+
+
+
+def get_file_sha256(path) -> str:
+    sha256 = hashlib.sha256()
+    with open(path, "rb") as f:
+        for chunk in iter(lambda: f.read(1024 * 1024), b""):
+            sha256.update(chunk)
+    return sha256.hexdigest()
+
+
+def get_builder_id() -> str:
+    # the ci run if built in github actions or gitlab ci, otherwise the host
+    if os.environ.get("GITHUB_ACTIONS") == "true":
+        server = os.environ.get("GITHUB_SERVER_URL", "https://github.com")
+        return f"{server}/{os.environ.get('GITHUB_REPOSITORY')}/actions/runs/{os.environ.get('GITHUB_RUN_ID')}"
+    if os.environ.get("GITLAB_CI") == "true":
+        return os.environ.get("CI_JOB_URL", "gitlab-ci")
+    return f"ccgo://{socket.gethostname()}"
+
+
+def get_toolchain_versions() -> dict:
+    # the toolchains the builds use, DEVELOPER_DIR and ANDROID_NDK_HOME set
+    # by the user win over the pins of .ccgo/toolchains.toml like in builds
+    try:
+        config = load_toolchain_config()
+    except (OSError, ValueError):
+        config = {}
+    versions = {}
+    ndk_dir = (os.environ.get("ANDROID_NDK_HOME") or os.environ.get("ANDROID_NDK_ROOT")
+               or config.get("ndk", {}).get("path"))
+    if ndk_dir:
+        versions["ndk"] = get_ndk_version(ndk_dir) or config.get("ndk", {}).get("version") or "unknown"
+    developer_dir = os.environ.get("DEVELOPER_DIR") or config.get("xcode", {}).get("path")
+    if developer_dir:
+        versions["xcode"] = get_xcode_version(developer_dir) or config.get("xcode", {}).get("version") or "unknown"
+    elif config.get("xcode", {}).get("version"):
+        versions["xcode"] = config["xcode"]["version"]
+    for name, toolchain in sorted(get_custom_toolchains(config).items()):
+        versions[f"{CUSTOM_TOOLCHAIN_PREFIX}{name}"] = dict(toolchain["values"], **toolchain["cmake"])
+    return versions
+
+
+def strip_url_credentials(url) -> str:
+    # origin of ci checkouts is like https://gitlab-ci-token:<token>@host/foo.git,
+    # never publish the token in the provenance
+    parts = urllib.parse.urlsplit(url)
+    if not parts.netloc or "@" not in parts.netloc:
+        return url
+    return urllib.parse.urlunsplit(parts._replace(netloc=parts.netloc.rpartition("@")[2]))
+
+
+def make_statement(archive, parameters) -> dict:
+    return {
+        "_type": STATEMENT_TYPE,
+        "subject": [{
+            "name": os.path.basename(archive),
+            "digest": {"sha256": get_file_sha256(archive)},
+        }],
+        "predicateType": PREDICATE_TYPE,
+        "predicate": {
+            "buildDefinition": {
+                "buildType": BUILD_TYPE,
+                "externalParameters": parameters,
+                "internalParameters": {
+                    "toolchains": get_toolchain_versions(),
+                    "workTreeClean": is_work_tree_clean(),
+                },
+                "resolvedDependencies": [{
+                    "uri": f"git+{strip_url_credentials(get_remote_url() or os.getcwd())}",
+                    "digest": {"gitCommit": get_head_revision()},
+                }],
+            },
+            "runDetails": {
+                "builder": {"id": get_builder_id()},
+                "metadata": {
+                    "finishedOn": datetime.datetime.now(datetime.timezone.utc).strftime("%Y-%m-%dT%H:%M:%SZ"),
+                },
+            },
+        },
+    }
+
+
+def sign_file(path, key) -> int:
+    return exec_command(f"openssl dgst -sha256 -sign \"{key}\" -out \"{path}{SIGNATURE_SUFFIX}\" \"{path}\"")[0]
+
+
+def verify_file_signature(path, public_key) -> bool:
+    err_code, output = exec_command(
+        f"openssl dgst -sha256 -verify \"{public_key}\" -signature \"{path}{SIGNATURE_SUFFIX}\" \"{path}\"")
+    return err_code == 0
+
+
+def verify_statement(statement, archive, revision=None) -> list:
+    # return error messages, empty if the statement matches the archive
+    errors = []
+    if statement.get("_type") != STATEMENT_TYPE or statement.get("predicateType") != PREDICATE_TYPE:
+        errors.append("not a slsa provenance statement")
+        return errors
+    name = os.path.basename(archive)
+    subject = next((x for x in statement.get("subject", []) if x.get("name") == name), None)
+    if subject is None:
+        errors.append(f"{name} is not a subject of the statement")
+    elif subject.get("digest", {}).get("sha256") != get_file_sha256(archive):
+        errors.append(f"sha256 of {name} does not match the statement")
+    if revision:
+        dependencies = statement["predicate"]["buildDefinition"].get("resolvedDependencies", [])
+        commits = [x.get("digest", {}).get("gitCommit") or "" for x in dependencies]
+        if not any([x.startswith(revision) for x in commits if x]):
+            errors.append(f"built from {commits}, not revision {revision}")
+    return errors
@@ -0,0 +1,47 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import json
+import time
+
+STATE_DIR = ".ccgo"
+STATE_FILE = "state.json"
+
+
+def get_state_path(project_dir=".") -> str:
+    return os.path.join(project_dir, STATE_DIR, STATE_FILE)
+
+
+def load_build_state(project_dir=".") -> dict:
+    # {platform: {"success", "exit_code", "time"}} of the last `build all`
+    state_path = get_state_path(project_dir)
+    if not os.path.exists(state_path):
+        return {}
+    try:
+        with open(state_path, "r") as f:
+            return json.load(f).get("platforms", {})
+    except ValueError:
+        return {}
+
+
+def save_build_state(platforms, project_dir="."):
+    state_path = get_state_path(project_dir)
+    os.makedirs(os.path.dirname(state_path), exist_ok=True)
+    # write then rename, an interrupted build must not corrupt the state
+    tmp_path = state_path + ".tmp"
+    with open(tmp_path, "w") as f:
+        json.dump({"platforms": platforms}, f, indent=2)
+    os.replace(tmp_path, state_path)
+
+
+def make_platform_state(err_code) -> dict:
+    return {"success": err_code == 0, "exit_code": err_code, "time": int(time.time())}
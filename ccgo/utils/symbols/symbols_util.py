@@ -0,0 +1,86 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import sys
+import glob
+import shutil
+import subprocess
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.cmd.cmd_util import exec_command
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+STATIC_LIB_EXTENSIONS = [".a", ".lib"]
+# strong definitions in text, data, bss and read-only data sections, and
+# the other sections of mach-o (S), weak (W/V), common (C) and undefined
+# (U) symbols can be defined twice
+STRONG_SYMBOL_TYPES = ["T", "D", "B", "R", "S"]
+# "lib.a[obj.o]: symbol type value size" of nm -P -A
+NM_PORTABLE_PATTERN = re.compile(r"^(?P<lib>.+?)(?:\[(?P<obj>[^\]]+)\])?: (?P<symbol>\S+) (?P<type>\w)(?: |$)")
+
+
+def find_static_libs(paths) -> list:
+    libs = []
+    for path in paths:
+        if os.path.isdir(path):
+            for ext in STATIC_LIB_EXTENSIONS:
+                libs.extend(glob.glob(os.path.join(path, "**", "*" + ext), recursive=True))
+        else:
+            libs.append(path)
+    return sorted(set(libs))
+
+
+def list_strong_symbols(lib, nm="nm") -> list:
+    # return [(symbol, object)] defined strongly in the lib, None if nm failed
+    err_code, output = exec_command(f"{nm} -A -g -P \"{lib}\"")
+    if err_code != 0:
+        logger.error(f"Failed to read symbols of {lib}:\n{output.strip()}")
+        return None
+    symbols = []
+    for line in output.splitlines():
+        match = NM_PORTABLE_PATTERN.match(line)
+        if match and match.group("type") in STRONG_SYMBOL_TYPES:
+            symbols.append((match.group("symbol"), match.group("obj") or os.path.basename(lib)))
+    return symbols
+
+
+def find_duplicate_symbols(libs, nm="nm"):
+    # return {symbol: [(lib, object), ...]} of symbols defined in more than
+    # one object, and the libs nm failed to read, the same object in the
+    # combined sdk lib and the lib of its module is not a duplicate
+    owners = {}
+    unreadable = []
+    for lib in libs:
+        symbols = list_strong_symbols(lib, nm)
+        if symbols is None:
+            unreadable.append(lib)
+            continue
+        for symbol, obj in symbols:
+            owners.setdefault(symbol, []).append((lib, obj))
+    return {k: v for k, v in owners.items() if len(set([obj for _, obj in v])) > 1}, unreadable
+
+
+def demangle(symbols) -> dict:
+    if not symbols or not shutil.which("c++filt"):
+        return {x: x for x in symbols}
+    output = subprocess.run(["c++filt"], input="\n".join(symbols), stdout=subprocess.PIPE,
+                            universal_newlines=True).stdout
+    return dict(zip(symbols, output.splitlines()))
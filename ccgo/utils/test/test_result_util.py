@@ -0,0 +1,50 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import glob
+import shutil
+import xml.etree.ElementTree as ET
+
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+TEST_RESULTS_DIR = os.path.join(".ccgo", "test_results")
+
+
+def get_test_result_env(results_dir=TEST_RESULTS_DIR) -> dict:
+    # googletest writes one xml report of each test executable it runs into
+    # the dir, a failed `build tests` with reports failed in the tests, not
+    # in the compile. a GTEST_OUTPUT set by the user is kept
+    if os.environ.get("GTEST_OUTPUT"):
+        return {}
+    return {"GTEST_OUTPUT": f"xml:{os.path.abspath(results_dir)}{os.sep}"}
+
+
+def clear_test_results(results_dir=TEST_RESULTS_DIR):
+    shutil.rmtree(results_dir, ignore_errors=True)
+
+
+def load_test_results(results_dir=TEST_RESULTS_DIR):
+    # return whether any tests ran, and the full names of the failed ones
+    reports = sorted(glob.glob(os.path.join(results_dir, "*.xml")))
+    failures = []
+    for report in reports:
+        try:
+            root = ET.parse(report).getroot()
+        except ET.ParseError as e:
+            logger.debug(f"Failed to parse {report}: {e}")
+            continue
+        for case in root.iter("testcase"):
+            if case.find("failure") is not None or case.find("error") is not None:
+                failures.append(f"{case.get('classname')}.{case.get('name')}")
+    return len(reports) > 0, failures
@@ -0,0 +1,81 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import sys
+import glob
+import shutil
+
+
+def find_android_home():
+    # ANDROID_HOME, then the default sdk dir of android studio
+    for name in ["ANDROID_HOME", "ANDROID_SDK_ROOT"]:
+        if os.environ.get(name):
+            return os.environ[name]
+    if sys.platform == "darwin":
+        default_dir = os.path.expanduser("~/Library/Android/sdk")
+    elif sys.platform == "win32":
+        default_dir = os.path.join(os.environ.get("LOCALAPPDATA", ""), "Android", "Sdk")
+    else:
+        default_dir = os.path.expanduser("~/Android/Sdk")
+    return default_dir if os.path.isdir(default_dir) else None
+
+
+def find_sdkmanager(android_home):
+    name = "sdkmanager.bat" if sys.platform == "win32" else "sdkmanager"
+    if android_home:
+        candidates = [os.path.join(android_home, "cmdline-tools", "latest", "bin", name)]
+        candidates += sorted(glob.glob(os.path.join(android_home, "cmdline-tools", "*", "bin", name)), reverse=True)
+        candidates += [os.path.join(android_home, "tools", "bin", name)]
+        for candidate in candidates:
+            if os.path.isfile(candidate):
+                return candidate
+    return shutil.which(name)
+
+
+def get_ndk_version(ndk_dir):
+    # Pkg.Revision of source.properties, like 26.1.10909125
+    properties = os.path.join(ndk_dir, "source.properties")
+    if not os.path.isfile(properties):
+        return None
+    with open(properties, "r") as f:
+        for line in f:
+            key, sep, value = line.partition("=")
+            if sep and key.strip() == "Pkg.Revision":
+                return value.strip()
+    return None
+
+
+def list_installed_ndks(android_home) -> list:
+    # return [(version, ndk_dir), ...] of the side by side ndks and ndk-bundle
+    if not android_home:
+        return []
+    ndks = []
+    for ndk_dir in sorted(glob.glob(os.path.join(android_home, "ndk", "*"))) + [os.path.join(android_home, "ndk-bundle")]:
+        version = get_ndk_version(ndk_dir)
+        if version:
+            ndks.append((version, ndk_dir))
+    return ndks
+
+
+def parse_ndk_version(version) -> tuple:
+    # "26.10.1" sorts after "26.2.1", not before it as text
+    return tuple(int(x) for x in re.findall(r"\d+", version))
+
+
+def find_ndk_dir(android_home, version):
+    # the newest of the matches, "26" matches 26.x, "26.1" matches 26.1.x
+    matches = [(installed_version, ndk_dir) for installed_version, ndk_dir in list_installed_ndks(android_home)
+               if installed_version == version or installed_version.startswith(version + ".")]
+    if not matches:
+        return None
+    return max(matches, key=lambda x: parse_ndk_version(x[0]))[1]
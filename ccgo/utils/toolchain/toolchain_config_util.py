@@ -0,0 +1,156 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import re
+import sys
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+TOOLCHAINS_CONFIG_PATH = os.path.join(".ccgo", "toolchains.toml")
+# environment variables the build scripts read each toolchain from
+TOOLCHAIN_ENV_NAMES = {
+    "ndk": ["ANDROID_NDK_HOME", "ANDROID_NDK_ROOT"],
+    "xcode": ["DEVELOPER_DIR"],
+}
+# custom cross toolchains are registered as [toolchains.<name>], with the
+# cmake variables of it in [toolchains.<name>.cmake]
+CUSTOM_TOOLCHAIN_PREFIX = "toolchains."
+CUSTOM_TOOLCHAIN_DIR = os.path.join(".ccgo", "generated", "toolchains")
+CUSTOM_TOOLCHAIN_VARIABLES = {
+    "c_compiler": "CMAKE_C_COMPILER",
+    "cxx_compiler": "CMAKE_CXX_COMPILER",
+    "sysroot": "CMAKE_SYSROOT",
+    "system_name": "CMAKE_SYSTEM_NAME",
+    "system_processor": "CMAKE_SYSTEM_PROCESSOR",
+}
+# only the subset of toml written by save_toolchain_config
+SECTION_PATTERN = re.compile(r"^\[([\w.-]+)\]$")
+KEY_VALUE_PATTERN = re.compile(r'^([\w-]+)\s*=\s*"((?:[^"\\]|\\.)*)"$')
+
+
+def load_toolchain_config(path=TOOLCHAINS_CONFIG_PATH) -> dict:
+    # return {kind: {"version", "path"}}
+    if not os.path.isfile(path):
+        return {}
+    config = {}
+    section = None
+    with open(path, "r") as f:
+        for lineno, line in enumerate(f, 1):
+            line = line.strip()
+            if not line or line.startswith("#"):
+                continue
+            match = SECTION_PATTERN.match(line)
+            if match:
+                section = config.setdefault(match.group(1), {})
+                continue
+            match = KEY_VALUE_PATTERN.match(line)
+            if not match or section is None:
+                raise ValueError(f"{path}:{lineno}: unsupported line {line}")
+            section[match.group(1)] = match.group(2).replace('\\"', '"').replace("\\\\", "\\")
+    return config
+
+
+def save_toolchain_config(config, path=TOOLCHAINS_CONFIG_PATH):
+    lines = ["# toolchains pinned by `ccgo toolchain`"]
+    for kind, values in sorted(config.items()):
+        lines.append(f"\n[{kind}]")
+        for key, value in sorted(values.items()):
+            value = str(value).replace("\\", "\\\\").replace('"', '\\"')
+            lines.append(f'{key} = "{value}"')
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    with open(path, "w") as f:
+        f.write("\n".join(lines) + "\n")
+
+
+def set_toolchain(kind, version, toolchain_path):
+    config = load_toolchain_config()
+    config[kind] = {"version": version, "path": toolchain_path}
+    save_toolchain_config(config)
+
+
+def apply_toolchain_config():
+    # export the pinned toolchains, if the user set any variable of a kind,
+    # all of them are left alone to never mix two ndks
+    try:
+        config = load_toolchain_config()
+    except (OSError, ValueError) as e:
+        logger.warning(f"Ignore {TOOLCHAINS_CONFIG_PATH}: {e}")
+        return
+    for kind, values in config.items():
+        names = TOOLCHAIN_ENV_NAMES.get(kind, [])
+        if not values.get("path") or not names:
+            continue
+        user_names = [x for x in names if os.environ.get(x)]
+        if user_names:
+            logger.info(f"Pinned {kind} {values.get('version')} is overridden by {user_names} of the environment")
+            continue
+        for name in names:
+            os.environ[name] = values["path"]
+        logger.info(f"Using {kind} {values.get('version')} at {values['path']}")
+
+
+def get_custom_toolchains(config) -> dict:
+    # return {name: {"values": {key: value}, "cmake": {variable: value}}}
+    toolchains = {}
+    for section, values in config.items():
+        if not section.startswith(CUSTOM_TOOLCHAIN_PREFIX):
+            continue
+        name = section[len(CUSTOM_TOOLCHAIN_PREFIX):]
+        if name.endswith(".cmake"):
+            toolchains.setdefault(name[:-len(".cmake")], {"values": {}, "cmake": {}})["cmake"] = values
+        else:
+            toolchains.setdefault(name, {"values": {}, "cmake": {}})["values"] = values
+    return toolchains
+
+
+def quote_cmake_argument(value) -> str:
+    return '"' + str(value).replace("\\", "\\\\").replace('"', '\\"') + '"'
+
+
+def render_custom_toolchain_file(name, toolchain) -> str:
+    unknown = [x for x in toolchain["values"] if x not in CUSTOM_TOOLCHAIN_VARIABLES and x != "toolchain_file"]
+    if unknown:
+        raise ValueError(f"unsupported keys {unknown} of toolchain {name}, "
+                         f"use {sorted(CUSTOM_TOOLCHAIN_VARIABLES)} or toolchain_file")
+    lines = [f"# generated from [{CUSTOM_TOOLCHAIN_PREFIX}{name}] of {TOOLCHAINS_CONFIG_PATH}, do not edit"]
+    # the variables of the registration win over the included toolchain file
+    toolchain_file = toolchain["values"].get("toolchain_file")
+    if toolchain_file:
+        lines.append(f"include({quote_cmake_argument(os.path.abspath(toolchain_file))})")
+    variables = {CUSTOM_TOOLCHAIN_VARIABLES[k]: v for k, v in toolchain["values"].items() if k != "toolchain_file"}
+    for variable, value in sorted(variables.items()):
+        lines.append(f"set({variable} {quote_cmake_argument(value)})")
+    for variable, value in sorted(toolchain["cmake"].items()):
+        lines.append(f"set({variable} {quote_cmake_argument(value)} CACHE STRING \"\" FORCE)")
+    return "\n".join(lines) + "\n"
+
+
+def get_custom_toolchain_file(name) -> str:
+    return os.path.abspath(os.path.join(CUSTOM_TOOLCHAIN_DIR, f"{name}.cmake"))
+
+
+def write_custom_toolchain_file(name, toolchain) -> str:
+    content = render_custom_toolchain_file(name, toolchain)
+    path = get_custom_toolchain_file(name)
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    with open(path, "w") as f:
+        f.write(content)
+    return path
@@ -0,0 +1,76 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import json
+import shutil
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(SCRIPT_PATH)
+sys.path.append(PROJECT_ROOT_PATH)
+PACKAGE_NAME = os.path.basename(SCRIPT_PATH)
+# <<<<<<<<<<<<
+from utils.cmd.cmd_util import exec_command
+from utils.toolchain.ndk_util import find_android_home
+from utils.toolchain.ndk_util import list_installed_ndks
+from utils.toolchain.xcode_util import list_installed_xcodes
+
+COMPILER_NAMES = ["cc", "c++", "gcc", "g++", "clang", "clang++", "cmake", "ninja"]
+MINGW_NAMES = ["x86_64-w64-mingw32-gcc", "i686-w64-mingw32-gcc", "aarch64-w64-mingw32-clang"]
+VSWHERE_PATH = os.path.join(os.environ.get("ProgramFiles(x86)", "C:\\Program Files (x86)"),
+                            "Microsoft Visual Studio", "Installer", "vswhere.exe")
+
+
+def get_tool_version(path):
+    # first line of "<tool> --version"
+    err_code, output = exec_command(f"\"{path}\" --version")
+    if err_code != 0 or not output.strip():
+        return None
+    return output.strip().splitlines()[0]
+
+
+def detect_path_tools(kind, names) -> list:
+    toolchains = []
+    for name in names:
+        path = shutil.which(name)
+        if path:
+            toolchains.append({"kind": kind, "name": name, "version": get_tool_version(path), "path": path})
+    return toolchains
+
+
+def detect_msvc() -> list:
+    if sys.platform != "win32" or not os.path.isfile(VSWHERE_PATH):
+        return []
+    err_code, output = exec_command(
+        f"\"{VSWHERE_PATH}\" -products * -requires Microsoft.VisualStudio.Component.VC.Tools.x86.x64 -format json")
+    if err_code != 0:
+        return []
+    try:
+        installations = json.loads(output)
+    except ValueError:
+        return []
+    return [{"kind": "msvc", "name": x.get("displayName", "msvc"), "version": x.get("installationVersion"),
+             "path": x.get("installationPath")} for x in installations]
+
+
+def detect_toolchains() -> list:
+    # return [{"kind", "name", "version", "path"}] of all toolchains found on this host
+    toolchains = detect_path_tools("compiler", COMPILER_NAMES)
+    toolchains += [{"kind": "ndk", "name": "ndk", "version": version, "path": path}
+                   for version, path in list_installed_ndks(find_android_home())]
+    toolchains += [{"kind": "xcode", "name": "xcode", "version": version, "path": path}
+                   for version, path in list_installed_xcodes()]
+    toolchains += detect_path_tools("mingw", MINGW_NAMES)
+    toolchains += detect_msvc()
+    return toolchains
@@ -0,0 +1,50 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import glob
+import plistlib
+
+DEFAULT_APPLICATIONS_DIR = "/Applications"
+
+
+def get_xcode_version(developer_dir):
+    # CFBundleShortVersionString of Xcode.app/Contents/version.plist
+    version_plist = os.path.join(os.path.dirname(developer_dir.rstrip("/")), "version.plist")
+    if not os.path.isfile(version_plist):
+        return None
+    with open(version_plist, "rb") as f:
+        return plistlib.load(f).get("CFBundleShortVersionString")
+
+
+def list_installed_xcodes(applications_dir=DEFAULT_APPLICATIONS_DIR) -> list:
+    # return [(version, developer_dir), ...] of all installed Xcode.app
+    xcodes = []
+    for app_path in sorted(glob.glob(os.path.join(applications_dir, "Xcode*.app"))):
+        developer_dir = os.path.join(app_path, "Contents", "Developer")
+        version = get_xcode_version(developer_dir)
+        if version:
+            xcodes.append((version, developer_dir))
+    return xcodes
+
+
+def find_xcode(version, applications_dir=DEFAULT_APPLICATIONS_DIR):
+    # return (installed version, developer_dir) or None,
+    # "15" matches 15.x, "15.2" matches 15.2 and 15.2.x
+    for installed_version, developer_dir in list_installed_xcodes(applications_dir):
+        if installed_version == version or installed_version.startswith(version + "."):
+            return installed_version, developer_dir
+    return None
+
+
+def find_xcode_developer_dir(version, applications_dir=DEFAULT_APPLICATIONS_DIR):
+    xcode = find_xcode(version, applications_dir)
+    return xcode[1] if xcode else None
@@ -0,0 +1,112 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import json
+import time
+import shlex
+
+from utils.env.env_util import mask_env_value
+from utils.env.env_util import is_secret_key
+from utils.log.log_util import get_logger
+
+logger = get_logger()
+
+TRACE_DIR = ".ccgo"
+TRACE_FILE = "trace.jsonl"
+
+# path of the trace and the environment ccgo started with, set by start_trace
+_trace_path = None
+_base_env = {}
+
+
+def get_trace_path(project_dir=".") -> str:
+    return os.path.join(project_dir, TRACE_DIR, TRACE_FILE)
+
+
+def start_trace(project_dir="."):
+    # every traced invocation starts a new trace
+    global _trace_path, _base_env
+    _trace_path = os.path.abspath(get_trace_path(project_dir))
+    _base_env = dict(os.environ)
+    os.makedirs(os.path.dirname(_trace_path), exist_ok=True)
+    open(_trace_path, "w").close()
+
+
+def is_tracing() -> bool:
+    return _trace_path is not None
+
+
+def get_env_delta() -> dict:
+    # variables set or changed by ccgo since the start, like CFLAGS or DEVELOPER_DIR,
+    # secrets like the tokens of --env are masked and read again on replay
+    return {k: mask_env_value(k, v) for k, v in os.environ.items() if _base_env.get(k) != v}
+
+
+def get_replay_env(step):
+    # return the environment to replay the step, and the names of the masked
+    # secrets missing in the current environment
+    env = dict(os.environ)
+    missing = []
+    for name, value in step["env"].items():
+        if is_secret_key(name):
+            if name not in os.environ:
+                missing.append(name)
+            continue
+        env[name] = value
+    return env, sorted(missing)
+
+
+def record_command(command, cwd, duration_second, exit_code):
+    # command is a shell string or a list of arguments
+    if not is_tracing():
+        return
+    if isinstance(command, str):
+        try:
+            arguments = shlex.split(command)
+        except ValueError:
+            arguments = [command]
+    else:
+        arguments = list(command)
+        command = " ".join([shlex.quote(x) for x in arguments])
+    record = {
+        "time": int(time.time()),
+        "program": arguments[0] if arguments else "",
+        "args": arguments[1:],
+        "command": command,
+        "cwd": os.path.abspath(cwd or os.getcwd()),
+        "env": get_env_delta(),
+        "duration_ms": int(duration_second * 1000),
+        "exit_code": exit_code,
+    }
+    try:
+        with open(_trace_path, "a") as f:
+            f.write(json.dumps(record) + "\n")
+    except OSError as e:
+        # tracing must never break the command
+        logger.debug(f"Failed to write {_trace_path}: {e}")
+
+
+def load_trace(path) -> list:
+    # steps are numbered from 1 in the order they ran
+    if not os.path.exists(path):
+        return []
+    steps = []
+    with open(path, "r") as f:
+        for line in f:
+            try:
+                steps.append(json.loads(line))
+            except ValueError:
+                # skip the line broken by an interrupted write
+                continue
+    for index, step in enumerate(steps):
+        step["step"] = index + 1
+    return steps
@@ -0,0 +1,43 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import unittest
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.cache.cache_util import select_prune_entries
+
+
+def make_entry(path, size, last_used) -> dict:
+    return {"path": path, "kind": "build", "size": size, "last_used": last_used}
+
+
+class SelectPruneEntriesTest(unittest.TestCase):
+    def test_keep_the_least_recently_used_over_max_size(self):
+        entries = [make_entry(os.path.join("cmake_build", "ios"), 100, 1),
+                   make_entry(os.path.join("cmake_build", "linux"), 100, 2)]
+        selected = select_prune_entries(entries, max_size=100)
+        self.assertEqual([x["path"] for x in selected], [os.path.join("cmake_build", "ios")])
+
+    def test_keep_platform_dir_of_another_case(self):
+        entries = [make_entry(os.path.join("cmake_build", "Android"), 100, 1),
+                   make_entry(os.path.join("cmake_build", "linux"), 100, 2)]
+        selected = select_prune_entries(entries, max_size=0, keep=[os.path.join("cmake_build", "android")])
+        self.assertEqual([x["path"] for x in selected], [os.path.join("cmake_build", "linux")])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,47 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import tempfile
+import subprocess
+import unittest
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+
+MAIN_PATH = os.path.join(PROJECT_ROOT_PATH, "ccgo", "main.py")
+
+
+class CiInitTest(unittest.TestCase):
+    def generate(self, provider, config_path) -> str:
+        with tempfile.TemporaryDirectory() as work_dir:
+            subprocess.run(
+                [sys.executable, MAIN_PATH, "ci", "init", "--provider", provider, "--platforms", "android,linux"],
+                cwd=work_dir, check=True, stdout=subprocess.PIPE, stderr=subprocess.PIPE)
+            with open(os.path.join(work_dir, config_path), "r") as f:
+                return f.read()
+
+    def test_github_uploads_sdk_zips(self):
+        content = self.generate("github", os.path.join(".github", "workflows", "ccgo.yml"))
+        self.assertIn("actions/upload-artifact", content)
+        self.assertIn("path: ./bin/*.zip", content)
+
+    def test_gitlab_uploads_sdk_zips(self):
+        content = self.generate("gitlab", ".gitlab-ci.yml")
+        self.assertEqual(content.count("- ./bin/*.zip"), 2)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,53 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import tempfile
+import subprocess
+import unittest
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from cli import strip_global_options
+
+MAIN_PATH = os.path.join(PROJECT_ROOT_PATH, "ccgo", "main.py")
+
+
+class StripGlobalOptionsTest(unittest.TestCase):
+    def test_option_before_target(self):
+        self.assertEqual(strip_global_options(["build", "--log-level", "debug", "linux"]), ["build", "linux"])
+
+    def test_option_before_subcommand(self):
+        self.assertEqual(strip_global_options(["--locale", "zh-CN", "build", "android"]), ["build", "android"])
+
+    def test_option_with_equals_and_flag(self):
+        argv = ["symbols", "duplicates", "--log-file=ccgo.log", "--trace", "a.a"]
+        self.assertEqual(strip_global_options(argv), ["symbols", "duplicates", "a.a"])
+
+    def test_keep_args_after_double_dash(self):
+        argv = ["profile", "--log-level", "debug", "--", "./bench", "--log-level", "x"]
+        self.assertEqual(strip_global_options(argv), ["profile", "--", "./bench", "--log-level", "x"])
+
+    def test_global_option_before_positional_target(self):
+        with tempfile.TemporaryDirectory() as work_dir:
+            process = subprocess.run(
+                [sys.executable, MAIN_PATH, "journal", "--log-level", "debug", "show"],
+                cwd=work_dir, stdout=subprocess.PIPE, stderr=subprocess.PIPE, universal_newlines=True)
+        self.assertEqual(process.returncode, 0, process.stderr)
+        self.assertIn("No invocations found", process.stdout)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,39 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import unittest
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.compile_db.compile_db_util import strip_output_arguments
+
+
+class StripOutputArgumentsTest(unittest.TestCase):
+    def test_gcc_like(self):
+        arguments = ["clang++", "-MD", "-MT", "foo.o", "-MF", "foo.o.d", "-o", "foo.o", "-c", "foo.cc"]
+        self.assertEqual(strip_output_arguments(arguments), ["clang++", "foo.cc"])
+
+    def test_joined_output_but_not_options(self):
+        arguments = ["clang", "-ofoo.o", "-objcmt-migrate-literals", "-openmp", "-O2", "-c", "foo.c"]
+        self.assertEqual(strip_output_arguments(arguments), ["clang", "-objcmt-migrate-literals", "-openmp", "-O2", "foo.c"])
+
+    def test_keep_runtime_flags_of_clang_cl(self):
+        arguments = ["C:\\LLVM\\bin\\clang-cl.exe", "-MTd", "/MD", "-MT", "/Fofoo.obj", "/c", "foo.cc"]
+        self.assertEqual(strip_output_arguments(arguments), ["C:\\LLVM\\bin\\clang-cl.exe", "-MTd", "/MD", "-MT", "foo.cc"])
+
+
+if __name__ == "__main__":
+    unittest.main()
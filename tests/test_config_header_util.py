@@ -0,0 +1,50 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import tempfile
+import unittest
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.config.config_header_util import get_archive_header_name
+from utils.config.config_header_util import remove_stale_cmake_caches
+
+
+class StaleCMakeCacheTest(unittest.TestCase):
+    def write_cache(self, build_dir, name, flags):
+        os.makedirs(os.path.join(build_dir, name))
+        with open(os.path.join(build_dir, name, "CMakeCache.txt"), "w") as f:
+            f.write(f"CMAKE_C_FLAGS:STRING={flags}\n")
+
+    def test_only_caches_without_the_include_dir(self):
+        include_dir = "/project dir/.ccgo/generated/linux/include"
+        with tempfile.TemporaryDirectory() as build_dir:
+            self.write_cache(build_dir, "old", "-O2")
+            self.write_cache(build_dir, "new", f"-O2 -I\"{include_dir}\"")
+            removed = remove_stale_cmake_caches(build_dir, include_dir)
+            self.assertEqual(removed, [os.path.join(build_dir, "old", "CMakeCache.txt")])
+            self.assertTrue(os.path.isfile(os.path.join(build_dir, "new", "CMakeCache.txt")))
+
+
+class ArchiveHeaderNameTest(unittest.TestCase):
+    def test_next_to_build_info(self):
+        self.assertEqual(get_archive_header_name("foo_SDK/build_info.json"), "foo_SDK/include/ccgo_config.h")
+        self.assertEqual(get_archive_header_name("build_info.json"), "include/ccgo_config.h")
+        self.assertEqual(get_archive_header_name(None), "include/ccgo_config.h")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,57 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import unittest
+from unittest import mock
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.crash import crash_util
+from utils.crash.crash_util import BACKTRACE_FRAME_PATTERN
+
+
+class BacktraceFrameTest(unittest.TestCase):
+    def test_module_offset(self):
+        match = BACKTRACE_FRAME_PATTERN.search("/usr/lib/libfoo.so(+0x1a2b) [0x7f0000001a2b]")
+        self.assertEqual(match.group("module"), "/usr/lib/libfoo.so")
+        self.assertEqual(match.group("address"), "1a2b")
+
+    def test_symbol_offset_not_matched(self):
+        self.assertIsNone(BACKTRACE_FRAME_PATTERN.search("/usr/lib/libfoo.so(foo_open+0x10) [0x7f0000001a2b]"))
+
+    def test_symbolicate_only_module_offsets(self):
+        text = "./app(+0x1234) [0x55551234]\n./libfoo.so(foo_open+0x10) [0x7f0000001a2b]\n"
+        with mock.patch.object(crash_util, "find_symbol_file", return_value="libfoo.so.debug"), \
+                mock.patch.object(crash_util, "symbolize_elf", return_value=["main at main.cc:3"]) as symbolize:
+            output, resolved, total = crash_util.symbolicate(text, {})
+        symbolize.assert_called_once_with("libfoo.so.debug", "1234")
+        self.assertEqual((resolved, total), (1, 1))
+        self.assertIn("foo_open+0x10", output)
+
+
+class DumpSymsInputTest(unittest.TestCase):
+    def test_libs_and_debug_files(self):
+        for path in ["arm64-v8a/libfoo.so", "libfoo.so.1", "foo.dylib", "foo.dll", "foo.pdb",
+                     os.path.join("foo.framework.dSYM", "Contents", "Resources", "DWARF", "foo")]:
+            self.assertTrue(crash_util.is_dump_syms_input(path), path)
+
+    def test_other_files(self):
+        for path in ["README.md", "mapping.txt", "libfoo.a", os.path.join("foo.dSYM", "Contents", "Info.plist")]:
+            self.assertFalse(crash_util.is_dump_syms_input(path), path)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,46 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import unittest
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.error.exit_code import ExitCode
+from utils.error.error_code import ERROR_CODES
+from utils.error.error_code import SUBSYSTEM_ERROR_CODES
+from utils.error.error_code import exit_with_error
+from utils.error.error_code import find_error
+
+
+class SubsystemErrorCodeTest(unittest.TestCase):
+    def test_exit_with_the_category(self):
+        with self.assertRaises(SystemExit) as context:
+            exit_with_error("E0203")
+        self.assertEqual(context.exception.code, ExitCode.PACKAGING_ERROR)
+        self.assertEqual(context.exception.error_code, "E0203")
+
+    def test_every_code_has_a_category(self):
+        for code, error in SUBSYSTEM_ERROR_CODES.items():
+            self.assertIn(error["exit_code"], ERROR_CODES, code)
+
+    def test_find_error(self):
+        self.assertEqual(find_error("e0101")[0], "E0101")
+        self.assertEqual(find_error("4")[0], "E0004")
+        self.assertEqual(find_error("E0199"), (None, None))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,44 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import unittest
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from commands.fetch import Fetch
+from utils.package.package_util import is_sdk_archive_of
+
+
+class FindArchiveTest(unittest.TestCase):
+    def test_sdk_next_to_symbols(self):
+        release = {"assets": [
+            {"name": "foo_ANDROID_SDK-1.2.0.zip"},
+            {"name": "foo_ANDROID_SYMBOLS-1.2.0.zip"},
+            {"name": "foo_IOS_SDK-1.2.0.zip"},
+            {"name": "SHA256SUMS"},
+        ]}
+        self.assertEqual(Fetch().find_archive(release, "android")["name"], "foo_ANDROID_SDK-1.2.0.zip")
+
+    def test_platform_is_a_whole_segment(self):
+        self.assertTrue(is_sdk_archive_of("foo_MACOS_SDK-1.2.0.zip", "macos"))
+        self.assertFalse(is_sdk_archive_of("foo_MACOS_SDK-1.2.0.zip", "os"))
+        self.assertFalse(is_sdk_archive_of("foo_MACOS_SDK-1.2.0.zip", "ios"))
+        self.assertFalse(is_sdk_archive_of("foo_IOS_SYMBOLS-1.2.0.zip", "ios"))
+        self.assertFalse(is_sdk_archive_of("foo_IOS_SDK-1.2.0.zip.sha256", "ios"))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,60 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import unittest
+from unittest import mock
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.github import github_util
+from utils.github.github_util import GitHubClient
+
+
+class UpdateReleaseTest(unittest.TestCase):
+    def update_payload(self, **kwargs) -> dict:
+        with mock.patch.object(github_util, "http_request_json", return_value=(200, {})) as request:
+            GitHubClient("owner/foo", "token").update_release(1, **kwargs)
+        return request.call_args[0][3]
+
+    def test_only_given_marks(self):
+        self.assertEqual(self.update_payload(draft=False), {"draft": False})
+        self.assertEqual(self.update_payload(prerelease=True), {"prerelease": True})
+
+    def test_keep_marks_by_default(self):
+        self.assertEqual(self.update_payload(), {})
+
+
+
+class GetReleaseByTagTest(unittest.TestCase):
+    def test_find_draft_in_the_list(self):
+        responses = [(404, {}), (200, [{"id": 1, "tag_name": "v1.0.0"}, {"id": 2, "tag_name": "v1.1.0", "draft": True}])]
+        with mock.patch.object(github_util, "http_request_json", side_effect=responses):
+            status, release = GitHubClient("owner/foo", "token").get_release_by_tag("v1.1.0")
+        self.assertEqual((status, release["id"]), (200, 2))
+
+    def test_not_found(self):
+        responses = [(404, {}), (200, [{"id": 1, "tag_name": "v1.0.0"}])]
+        with mock.patch.object(github_util, "http_request_json", side_effect=responses):
+            status, _ = GitHubClient("owner/foo", "token").get_release_by_tag("v1.1.0")
+        self.assertEqual(status, 404)
+
+    def test_origin_repo_from_remote_url(self):
+        with mock.patch.object(github_util, "get_remote_url", return_value="git@github.com:owner/foo.git"):
+            self.assertEqual(github_util.get_origin_repo(), "owner/foo")
+
+
+if __name__ == "__main__":
+    unittest.main()
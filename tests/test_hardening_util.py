@@ -0,0 +1,62 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import unittest
+from unittest import mock
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.hardening.hardening_util import check_elf
+from utils.hardening.hardening_util import check_symbols
+from utils.hardening.hardening_util import get_hardening_env
+from utils.hardening.hardening_util import get_fortify_header_path
+
+
+class StackProtectorTest(unittest.TestCase):
+    def test_canary(self):
+        self.assertEqual(check_symbols("U __stack_chk_fail", "")["stack_protector"], "yes")
+
+    def test_no_stack_buffers(self):
+        disassembly = "push %rbp\nmov %rsp,%rbp\nlea (%rdi,%rsi,1),%eax\nstp x29, x30, [sp, #-16]!\nmov x29, sp\nret"
+        self.assertEqual(check_symbols("T add", disassembly)["stack_protector"], "n/a")
+
+    def test_stack_buffers_without_canary(self):
+        for line in ["mov %rsp,%rdi", "lea -0x20(%rbp),%rax", "lea 0x10(%rsp),%rdi", "add x0, sp, #0x10"]:
+            self.assertEqual(check_symbols("U strcpy", line)["stack_protector"], "no", line)
+
+    def test_no_disassembly(self):
+        self.assertEqual(check_symbols("U strcpy")["stack_protector"], "no")
+
+
+class FortifySourceTest(unittest.TestCase):
+    def test_fortify_only_through_the_header(self):
+        cflags = get_hardening_env("linux", {})["CFLAGS"]
+        self.assertNotIn("-D_FORTIFY_SOURCE", cflags)
+        self.assertIn(f"-include \"{get_fortify_header_path('linux')}\"", cflags)
+
+    def test_no_fortify_of_msvc(self):
+        self.assertNotIn("-include", get_hardening_env("windows", {})["CFLAGS"])
+
+
+class ToolFailureTest(unittest.TestCase):
+    def test_failed_readelf(self):
+        with mock.patch("utils.hardening.hardening_util.exec_command", return_value=(1, "")):
+            with self.assertRaisesRegex(RuntimeError, "readelf"):
+                check_elf("libfoo.so", "readelf", "objdump")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,44 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import tempfile
+import unittest
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.toolchain.ndk_util import find_ndk_dir
+
+
+class FindNdkDirTest(unittest.TestCase):
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        for version in ["25.2.9519653", "26.2.11394342", "26.10.1", "26.1.10909125"]:
+            ndk_dir = os.path.join(self.temp_dir.name, "ndk", version)
+            os.makedirs(ndk_dir)
+            with open(os.path.join(ndk_dir, "source.properties"), "w") as f:
+                f.write(f"Pkg.Desc = Android NDK\nPkg.Revision = {version}\n")
+
+    def test_newest_of_the_major(self):
+        self.assertEqual(os.path.basename(find_ndk_dir(self.temp_dir.name, "26")), "26.10.1")
+
+    def test_exact_prefix(self):
+        self.assertEqual(os.path.basename(find_ndk_dir(self.temp_dir.name, "26.1")), "26.1.10909125")
+        self.assertIsNone(find_ndk_dir(self.temp_dir.name, "27"))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,48 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import unittest
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.analyze.report_util import parse_diagnostics
+from utils.analyze.report_util import to_uri
+
+
+class ParseDiagnosticsTest(unittest.TestCase):
+    def test_windows_drive(self):
+        output = "C:\\src\\foo.cc:12:3: warning: unused variable 'x' [clang-diagnostic-unused-variable]"
+        diagnostics = parse_diagnostics("clang-tidy", output)
+        self.assertEqual(len(diagnostics), 1)
+        self.assertEqual(diagnostics[0]["file"], "C:\\src\\foo.cc")
+        self.assertEqual(diagnostics[0]["line"], 12)
+        self.assertEqual(diagnostics[0]["rule"], "clang-diagnostic-unused-variable")
+
+
+class ToUriTest(unittest.TestCase):
+    def test_relative_to_root(self):
+        root_dir = os.path.abspath(os.sep + "repo")
+        file_path = os.path.join(root_dir, "src", "foo.cc")
+        self.assertEqual(to_uri(file_path, root_dir), "src/foo.cc")
+
+    def test_outside_of_root(self):
+        root_dir = os.path.abspath(os.sep + "repo")
+        file_path = os.path.abspath(os.path.join(os.sep + "usr", "include", "foo.h"))
+        self.assertEqual(to_uri(file_path, root_dir), file_path.replace(os.sep, "/"))
+
+
+if __name__ == "__main__":
+    unittest.main()
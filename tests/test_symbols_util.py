@@ -0,0 +1,49 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import unittest
+from unittest import mock
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.symbols import symbols_util
+
+NM_OUTPUT = {
+    "libsdk.a": "libsdk.a[log.o]: _log_init T 0 10\nlibsdk.a[net.o]: _kTimeout S 0 4\n",
+    "liblog.a": "liblog.a[log.o]: _log_init T 0 10\n",
+    "libnet.a": "libnet.a[socket.o]: _kTimeout S 0 4\n",
+}
+
+
+class FindDuplicateSymbolsTest(unittest.TestCase):
+    def find(self, libs) -> dict:
+        def exec_command(command):
+            return 0, NM_OUTPUT[command.split('"')[1]]
+        with mock.patch.object(symbols_util, "exec_command", side_effect=exec_command):
+            duplicates, unreadable = symbols_util.find_duplicate_symbols(libs)
+        self.assertEqual(unreadable, [])
+        return duplicates
+
+    def test_same_object_in_the_combined_lib(self):
+        self.assertNotIn("_log_init", self.find(["libsdk.a", "liblog.a"]))
+
+    def test_macho_section_symbols(self):
+        duplicates = self.find(["libsdk.a", "libnet.a"])
+        self.assertEqual(duplicates["_kTimeout"], [("libsdk.a", "net.o"), ("libnet.a", "socket.o")])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,49 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import tempfile
+import unittest
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.test.test_result_util import load_test_results
+
+REPORT = """<?xml version="1.0" encoding="UTF-8"?>
+<testsuites tests="2" failures="1">
+  <testsuite name="Net" tests="2" failures="1">
+    <testcase name="Connect" classname="Net"/>
+    <testcase name="Timeout" classname="Net">
+      <failure message="expected 1"/>
+    </testcase>
+  </testsuite>
+</testsuites>
+"""
+
+
+class LoadTestResultsTest(unittest.TestCase):
+    def test_failed_tests(self):
+        with tempfile.TemporaryDirectory() as results_dir:
+            with open(os.path.join(results_dir, "foo_test.xml"), "w") as f:
+                f.write(REPORT)
+            self.assertEqual(load_test_results(results_dir), (True, ["Net.Timeout"]))
+
+    def test_no_reports_when_not_ran(self):
+        with tempfile.TemporaryDirectory() as results_dir:
+            self.assertEqual(load_test_results(results_dir), (False, []))
+
+
+if __name__ == "__main__":
+    unittest.main()
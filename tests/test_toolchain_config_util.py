@@ -0,0 +1,71 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import unittest
+from unittest import mock
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.toolchain import toolchain_config_util
+
+CONFIG = {"ndk": {"version": "26.1.10909125", "path": "/sdk/ndk/26.1.10909125"}}
+
+
+class ApplyToolchainConfigTest(unittest.TestCase):
+    def apply(self, env) -> dict:
+        with mock.patch.object(toolchain_config_util, "load_toolchain_config", return_value=CONFIG), \
+                mock.patch.dict(os.environ, env, clear=True):
+            toolchain_config_util.apply_toolchain_config()
+            return dict(os.environ)
+
+    def test_set_all_names_of_the_pin(self):
+        env = self.apply({})
+        self.assertEqual(env["ANDROID_NDK_HOME"], "/sdk/ndk/26.1.10909125")
+        self.assertEqual(env["ANDROID_NDK_ROOT"], "/sdk/ndk/26.1.10909125")
+
+    def test_user_variable_overrides_the_pin(self):
+        env = self.apply({"ANDROID_NDK_HOME": "/opt/ndk"})
+        self.assertEqual(env["ANDROID_NDK_HOME"], "/opt/ndk")
+        self.assertNotIn("ANDROID_NDK_ROOT", env)
+
+
+class CustomToolchainTest(unittest.TestCase):
+    CONFIG = {
+        "ndk": CONFIG["ndk"],
+        "toolchains.rpi": {"c_compiler": "/opt/rpi/bin/gcc", "sysroot": "C:\\rpi\\sysroot"},
+        "toolchains.rpi.cmake": {"ENABLE_NEON": "ON"},
+    }
+
+    def test_registered_toolchains(self):
+        toolchains = toolchain_config_util.get_custom_toolchains(self.CONFIG)
+        self.assertEqual(list(toolchains), ["rpi"])
+        self.assertEqual(toolchains["rpi"]["cmake"], {"ENABLE_NEON": "ON"})
+
+    def test_toolchain_file(self):
+        toolchain = toolchain_config_util.get_custom_toolchains(self.CONFIG)["rpi"]
+        content = toolchain_config_util.render_custom_toolchain_file("rpi", toolchain)
+        self.assertIn('set(CMAKE_C_COMPILER "/opt/rpi/bin/gcc")', content)
+        self.assertIn('set(CMAKE_SYSROOT "C:\\\\rpi\\\\sysroot")', content)
+        self.assertIn('set(ENABLE_NEON "ON" CACHE STRING "" FORCE)', content)
+
+    def test_unsupported_key(self):
+        toolchain = {"values": {"compiler": "gcc"}, "cmake": {}}
+        with self.assertRaises(ValueError):
+            toolchain_config_util.render_custom_toolchain_file("rpi", toolchain)
+
+
+if __name__ == "__main__":
+    unittest.main()
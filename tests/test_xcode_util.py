@@ -0,0 +1,46 @@
+#
+# Copyright 2024 zhlinh and ccgo Project Authors. All rights reserved.
+# Use of this source code is governed by a MIT-style
+# license that can be found at
+#
+# https://opensource.org/license/MIT
+#
+# The above copyright notice and this permission
+# notice shall be included in all copies or
+# substantial portions of the Software.
+
+import os
+import sys
+import plistlib
+import tempfile
+import unittest
+# setup path
+# >>>>>>>>>>>>>>
+SCRIPT_PATH = os.path.split(os.path.realpath(__file__))[0]
+PROJECT_ROOT_PATH = os.path.dirname(SCRIPT_PATH)
+sys.path.append(os.path.join(PROJECT_ROOT_PATH, "ccgo"))
+# <<<<<<<<<<<<<<
+from utils.toolchain.xcode_util import find_xcode
+
+
+class FindXcodeTest(unittest.TestCase):
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        for app, version in [("Xcode.app", "15.2"), ("Xcode-16.app", "16.0")]:
+            contents = os.path.join(self.temp_dir.name, app, "Contents")
+            os.makedirs(contents)
+            with open(os.path.join(contents, "version.plist"), "wb") as f:
+                plistlib.dump({"CFBundleShortVersionString": version}, f)
+
+    def test_installed_version_of_the_query(self):
+        version, developer_dir = find_xcode("15", self.temp_dir.name)
+        self.assertEqual(version, "15.2")
+        self.assertEqual(developer_dir, os.path.join(self.temp_dir.name, "Xcode.app", "Contents", "Developer"))
+
+    def test_not_installed(self):
+        self.assertIsNone(find_xcode("15.1", self.temp_dir.name))
+
+
+if __name__ == "__main__":
+    unittest.main()